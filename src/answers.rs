@@ -0,0 +1,316 @@
+use crate::{
+    report::quoted,
+    solution::{DayReport, InputType, Status},
+};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind, Write},
+};
+
+// Expected answers of a year, stored in `inputs/<year>/answers.toml` as
+//
+//     [day01.examples]
+//     part_1 = "142"
+//
+//     [day01.examples.b]
+//     part_2 = "281"
+//
+//     [day01.puzzles]
+//     part_1 = "55108"
+//     part_2 = "56324"
+//
+// where lettered examples get a table of their own. Only this subset
+// of TOML is read, and the file is rewritten sorted when recording.
+pub struct Answers {
+    path: String,
+    tables: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+pub fn answers_path(year: usize) -> String {
+    format!("inputs/{}/answers.toml", year)
+}
+
+fn table_name(day: usize, input_type: InputType, example: Option<&str>) -> String {
+    match example {
+        Some(example) => format!("day{:02}.{}.{}", day, input_type.dir(), example),
+        None => format!("day{:02}.{}", day, input_type.dir()),
+    }
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+// Parses a basic string (without its opening quote), returning the rest
+// of the line after the closing quote.
+fn parse_string(text: &str) -> Result<(String, &str), String> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &text[idx + 1..])),
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some('u') => {
+                    let hex = chars.by_ref().take(4).map(|(_, c)| c).collect::<String>();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid escape \\u{}", hex))?;
+                    value.push(c);
+                }
+                Some(c) => return Err(format!("invalid escape \\{}", c)),
+                None => return Err("unterminated string".to_string()),
+            },
+            c => value.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+fn parse_value(text: &str) -> Result<String, String> {
+    let (value, rest) = match text.strip_prefix('"') {
+        Some(text) => parse_string(text)?,
+        // Numbers are kept as written, as answers are compared as text.
+        None => {
+            let end = text.find([' ', '\t', '#']).unwrap_or(text.len());
+            let (number, rest) = text.split_at(end);
+            if number.parse::<i128>().is_err() {
+                return Err(format!("expected a string or integer, found {:?}", text));
+            }
+            (number.to_string(), rest)
+        }
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(format!("unexpected {:?} after the value", rest));
+    }
+    Ok(value)
+}
+
+fn parse(text: &str) -> Result<BTreeMap<String, BTreeMap<String, String>>, String> {
+    let mut tables = BTreeMap::<String, BTreeMap<String, String>>::new();
+    let mut table = None;
+    for (idx, line) in text.lines().enumerate() {
+        let error = |message: String| format!("line {}: {}", idx + 1, message);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .split_once(']')
+                .filter(|(_, rest)| rest.trim().is_empty() || rest.trim().starts_with('#'))
+                .map(|(name, _)| name.trim())
+                .ok_or_else(|| error(format!("invalid table header {:?}", line)))?;
+            if !name.split('.').all(is_bare_key) {
+                return Err(error(format!("invalid table name {:?}", name)));
+            }
+            tables.entry(name.to_string()).or_default();
+            table = Some(name.to_string());
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected `key = value`, found {:?}", line)))?;
+        let key = key.trim();
+        if !is_bare_key(key) {
+            return Err(error(format!("invalid key {:?}", key)));
+        }
+        let table = table
+            .as_ref()
+            .ok_or_else(|| error(format!("{:?} is not in a table", key)))?;
+        let value = parse_value(value.trim()).map_err(error)?;
+        if tables
+            .get_mut(table)
+            .unwrap()
+            .insert(key.to_string(), value)
+            .is_some()
+        {
+            return Err(error(format!("duplicate key {:?}", key)));
+        }
+    }
+    Ok(tables)
+}
+
+impl Answers {
+    // A year without an answers file has no recorded answers.
+    pub fn load(year: usize) -> io::Result<Self> {
+        let path = answers_path(year);
+        let tables = match fs::read_to_string(&path) {
+            Ok(text) => parse(&text).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path, tables })
+    }
+
+    pub fn get(
+        &self,
+        day: usize,
+        input_type: InputType,
+        example: Option<&str>,
+        part: usize,
+    ) -> Option<&str> {
+        self.tables
+            .get(&table_name(day, input_type, example))?
+            .get(&format!("part_{}", part))
+            .map(|answer| answer.as_str())
+    }
+
+    fn set(
+        &mut self,
+        day: usize,
+        input_type: InputType,
+        example: Option<&str>,
+        part: usize,
+        answer: &str,
+    ) {
+        self.tables
+            .entry(table_name(day, input_type, example))
+            .or_default()
+            .insert(format!("part_{}", part), answer.to_string());
+    }
+
+    fn to_text(&self) -> String {
+        self.tables
+            .iter()
+            .filter(|(_, answers)| !answers.is_empty())
+            .map(|(table, answers)| {
+                let answers = answers
+                    .iter()
+                    .map(|(key, answer)| format!("{} = {}\n", key, quoted(answer)))
+                    .collect::<String>();
+                format!("[{}]\n{}", table, answers)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn save(&self) -> io::Result<()> {
+        fs::write(&self.path, self.to_text())
+    }
+}
+
+fn confirm(question: &str) -> io::Result<bool> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut reply = String::new();
+    io::stdin().read_line(&mut reply)?;
+    Ok(matches!(reply.trim(), "y" | "Y" | "yes"))
+}
+
+// Records the answers of the reports that have none yet, and replaces
+// recorded answers that differ only once confirmed.
+pub fn record(reports: &[DayReport], input_type: InputType) -> io::Result<()> {
+    let mut years = reports
+        .iter()
+        .map(|report| report.year)
+        .collect::<Vec<usize>>();
+    years.sort();
+    years.dedup();
+    for year in years {
+        let mut answers = Answers::load(year)?;
+        let mut changed = false;
+        for report in reports.iter().filter(|report| report.year == year) {
+            if report.error.is_some() {
+                continue;
+            }
+            for (part, status) in [(1, &report.part_1.status), (2, &report.part_2.status)] {
+                let answer = match status {
+                    Status::Unknown(answer) => answer,
+                    Status::Fail(answer, expected)
+                        if confirm(&format!(
+                            "{} day {} part {} answered {:?}, but {:?} is expected. Record it instead?",
+                            year,
+                            report.label(),
+                            part,
+                            answer,
+                            expected
+                        ))? =>
+                    {
+                        answer
+                    }
+                    _ => continue,
+                };
+                answers.set(
+                    report.day,
+                    input_type,
+                    report.example.as_deref(),
+                    part,
+                    answer,
+                );
+                changed = true;
+                println!(
+                    "Recorded {} day {} part {}: {:?}",
+                    year,
+                    report.label(),
+                    part,
+                    answer
+                );
+            }
+        }
+        if changed {
+            answers.save()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::load(2022).unwrap();
+        let crt = answers.get(10, InputType::Puzzles, None, 2).unwrap();
+        assert_eq!(crt.lines().count(), 6);
+        answers.set(1, InputType::Examples, Some("b"), 2, "281");
+        answers.set(1, InputType::Examples, None, 1, "\"a\"\t\\ \u{7}");
+        assert_eq!(parse(&answers.to_text()).unwrap(), answers.tables);
+    }
+
+    #[test]
+    fn parse_values() {
+        let text = "# comment\n\
+            [day01.examples]\n\
+            part_1 = 142 # trailing\n\
+            part_2 = \"caf\\u00e9\\n\"\n\
+            \n\
+            [day01.examples.b]\n\
+            part_2 = \"281\"";
+        let tables = parse(text).unwrap();
+        assert_eq!(tables["day01.examples"]["part_1"], "142");
+        assert_eq!(tables["day01.examples"]["part_2"], "café\n");
+        assert_eq!(tables["day01.examples.b"]["part_2"], "281");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text| parse(text).unwrap_err();
+        assert_eq!(
+            error("[day01.puzzles]\npart_1 = 1\npart_1 = 2"),
+            "line 3: duplicate key \"part_1\""
+        );
+        assert_eq!(
+            error("[day01.puzzles"),
+            "line 1: invalid table header \"[day01.puzzles\""
+        );
+        assert_eq!(error("[day 01]"), "line 1: invalid table name \"day 01\"");
+        assert_eq!(error("part_1 = 1"), "line 1: \"part_1\" is not in a table");
+        assert_eq!(
+            error("[a]\npart_1 = \"\\uzzzz\""),
+            "line 2: invalid escape \\uzzzz"
+        );
+        assert_eq!(error("[a]\npart_1 = \"open"), "line 2: unterminated string");
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    env, fs,
+    io::{self, ErrorKind, Read, Write},
+    net::TcpStream,
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const SESSION_ENV: &str = "AOC_SESSION";
+const SESSION_FILE: &str = ".aoc_session";
+const LAST_FETCH_PATH: &str = "inputs/.last_fetch";
+const USER_AGENT: &str = "aoc_new input fetcher";
+
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    // Requests are spaced at least this far apart, also across runs,
+    // by keeping the time of the last one in `last_fetch_path`.
+    min_interval: Duration,
+    last_fetch_path: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+// Reads the session token from `AOC_SESSION`, or else from `.aoc_session`.
+fn session_token() -> Option<String> {
+    env::var(SESSION_ENV)
+        .ok()
+        .or_else(|| fs::read_to_string(SESSION_FILE).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn decode_chunked(mut body: &[u8]) -> io::Result<Vec<u8>> {
+    let invalid = || io::Error::new(ErrorKind::InvalidData, "malformed chunked body");
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .ok_or_else(invalid)?;
+        let size = std::str::from_utf8(&body[..line_end])
+            .ok()
+            .and_then(|line| usize::from_str_radix(line.split(';').next()?.trim(), 16).ok())
+            .ok_or_else(invalid)?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(decoded);
+        }
+        decoded.extend_from_slice(body.get(..size).ok_or_else(invalid)?);
+        body = body.get(size + 2..).ok_or_else(invalid)?;
+    }
+}
+
+fn parse_response(response: &[u8]) -> io::Result<Response> {
+    let invalid = |message: &str| io::Error::new(ErrorKind::InvalidData, message.to_string());
+    let header_end = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| invalid("response has no end of headers"))?;
+    let head = String::from_utf8_lossy(&response[..header_end]);
+    let mut body = response[header_end + 4..].to_vec();
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| invalid("response has no status"))?;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match (name.trim().to_ascii_lowercase().as_str(), value.trim()) {
+            ("transfer-encoding", value) if value.eq_ignore_ascii_case("chunked") => {
+                body = decode_chunked(&body)?;
+            }
+            ("content-length", value) => {
+                if let Ok(len) = value.parse::<usize>() {
+                    body.truncate(len);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+fn http_get(url: &str, session: &str) -> io::Result<Response> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| invalid_input(format!("{} is not an http:// URL", url)))?;
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => rest.split_at(idx),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n\r\n",
+        path, authority, USER_AGENT, session
+    )?;
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+    parse_response(&response)
+}
+
+// The standard library has no TLS, so HTTPS requests are left to curl.
+// The cookie is passed on stdin to keep it out of the process list.
+fn curl_get(url: &str, session: &str) -> io::Result<Response> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--user-agent", USER_AGENT])
+        .args(["--header", "@-", "--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| io::Error::new(err.kind(), format!("HTTPS requests need curl: {}", err)))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(format!("Cookie: session={}\n", session).as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+    Ok(Response {
+        status: status
+            .parse()
+            .map_err(|_| io::Error::new(ErrorKind::InvalidData, "curl gave no status"))?,
+        body: body.to_string(),
+    })
+}
+
+impl Fetcher {
+    // Takes the base URL from `AOC_BASE_URL` unless one is given.
+    pub fn new(base_url: Option<String>) -> Self {
+        Self {
+            base_url: base_url
+                .or_else(|| env::var(BASE_URL_ENV).ok())
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session: session_token(),
+            min_interval: Duration::from_secs(5),
+            last_fetch_path: LAST_FETCH_PATH.to_string(),
+        }
+    }
+
+    fn wait_politely(&self) -> io::Result<()> {
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+        let last_fetch = fs::read_to_string(&self.last_fetch_path)
+            .ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        let wait = last_fetch
+            .and_then(|last_fetch| (last_fetch + self.min_interval).checked_sub(now()))
+            .filter(|wait| !wait.is_zero());
+        if let Some(wait) = wait {
+            eprintln!("Waiting {:.1?} between requests", wait);
+            thread::sleep(wait);
+        }
+        if let Some(dir) = Path::new(&self.last_fetch_path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.last_fetch_path, now().as_millis().to_string())
+    }
+
+    // Downloads the puzzle input of the day to `path`, unless it is already
+    // there. Returns whether it was downloaded.
+    pub fn fetch(&self, year: usize, day: usize, path: &str, force: bool) -> io::Result<bool> {
+        if !force && fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(false);
+        }
+        let session = self.session.as_deref().ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::PermissionDenied,
+                format!(
+                    "no session token, set {} or write it to {}",
+                    SESSION_ENV, SESSION_FILE
+                ),
+            )
+        })?;
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        self.wait_politely()?;
+        let response = if url.starts_with("https://") {
+            curl_get(&url, session)?
+        } else {
+            http_get(&url, session)?
+        };
+        let message = response.body.lines().next().unwrap_or_default();
+        match response.status {
+            200 => {}
+            400 | 401 | 403 => {
+                return Err(io::Error::new(
+                    ErrorKind::PermissionDenied,
+                    format!(
+                        "the session token was rejected (HTTP {}): {}",
+                        response.status, message
+                    ),
+                ))
+            }
+            404 => {
+                return Err(io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("{} day {} has no input (yet): {}", year, day, message),
+                ))
+            }
+            status => {
+                return Err(io::Error::other(format!(
+                    "unexpected HTTP {} from {}: {}",
+                    status, url, message
+                )))
+            }
+        }
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Inputs are kept without their final newline.
+        fs::write(path, response.body.trim_end_matches('\n'))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        time::Instant,
+    };
+
+    // Serves `responses` in order, one per connection, sending back
+    // the head of every request it gets.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                while reader.read_line(&mut request).unwrap() > 2 {}
+                sender.send(request).unwrap();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> String {
+        let dir = env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.to_string_lossy().to_string()
+    }
+
+    fn fetcher(base_url: &str, session: Option<&str>, dir: &str) -> Fetcher {
+        Fetcher {
+            base_url: base_url.to_string(),
+            session: session.map(String::from),
+            min_interval: Duration::from_millis(300),
+            last_fetch_path: format!("{}/.last_fetch", dir),
+        }
+    }
+
+    #[test]
+    fn downloads_the_input() {
+        let (base_url, requests) = serve(vec![(200, "1 2\n3 4\n")]);
+        let dir = temp_dir("download");
+        let path = format!("{}/day01.txt", dir);
+        let fetcher = fetcher(&base_url, Some("token"), &dir);
+
+        assert!(fetcher.fetch(2024, 1, &path, false).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=token\r\n"));
+        assert!(request.contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+    }
+
+    #[test]
+    fn uses_the_cached_input() {
+        let dir = temp_dir("cached");
+        let path = format!("{}/day01.txt", dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "cached").unwrap();
+        // Nothing listens here, so any request would fail.
+        let fetcher = fetcher("http://127.0.0.1:1", Some("token"), &dir);
+
+        assert!(!fetcher.fetch(2024, 1, &path, false).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "cached");
+    }
+
+    #[test]
+    fn requires_a_session_token() {
+        let dir = temp_dir("no_session");
+        let fetcher = fetcher("http://127.0.0.1:1", None, &dir);
+
+        let err = fetcher
+            .fetch(2024, 1, &format!("{}/day01.txt", dir), false)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.to_string().contains(SESSION_ENV));
+    }
+
+    #[test]
+    fn reports_a_rejected_session_token() {
+        let (base_url, _requests) = serve(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        )]);
+        let dir = temp_dir("rejected");
+        let path = format!("{}/day01.txt", dir);
+        let fetcher = fetcher(&base_url, Some("expired"), &dir);
+
+        let err = fetcher.fetch(2024, 1, &path, false).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+        assert!(err.to_string().contains("Please log in"));
+        assert!(!Path::new(&path).exists());
+    }
+
+    #[test]
+    fn spaces_out_requests() {
+        let (base_url, _requests) = serve(vec![(200, "first"), (200, "second")]);
+        let dir = temp_dir("rate_limit");
+        let path = format!("{}/day01.txt", dir);
+        let fetcher = fetcher(&base_url, Some("token"), &dir);
+
+        let start = Instant::now();
+        fetcher.fetch(2024, 1, &path, true).unwrap();
+        fetcher.fetch(2024, 1, &path, true).unwrap();
+        // The time of the last request is only kept to the millisecond.
+        assert!(start.elapsed() + Duration::from_millis(1) >= fetcher.min_interval);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+    }
+
+    #[test]
+    fn decodes_chunked_responses() {
+        let response = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nabcd\r\n2\r\nef\r\n0\r\n\r\n";
+        let response = parse_response(response).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "abcdef");
+    }
+}
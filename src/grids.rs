@@ -1,10 +1,14 @@
 use std::{
+    collections::HashMap,
+    fmt::{self, Display},
     iter::StepBy,
     ops::{Add, Div, Sub},
     slice::Iter,
 };
 
-use num::Num;
+use num::{Num, ToPrimitive};
+
+use crate::parsing::{ParseError, ParseResult};
 
 pub type UPoint = Point<usize>;
 pub type IPoint = Point<isize>;
@@ -94,6 +98,7 @@ where
         }
         positions
     }
+    #[allow(dead_code)]
     pub fn cross(&self, other: Self) -> T {
         self.x * other.y - self.y * other.x
     }
@@ -138,6 +143,7 @@ pub enum Dir {
     Left,
 }
 
+#[allow(dead_code)]
 pub enum TurnDir {
     Right,
     Left,
@@ -172,6 +178,7 @@ impl Dir {
             Dir::Left => Dir::Down,
         }
     }
+    #[allow(dead_code)]
     pub fn turn(&self, turn: TurnDir) -> Dir {
         match turn {
             TurnDir::Right => self.clockwise(),
@@ -190,6 +197,74 @@ impl<T> Grid<T> {
     pub fn from_vec(vec: Vec<T>, width: usize) -> Self {
         Self { vec, width }
     }
+    // Parses a grid with a line of text per row, mapping every character
+    // to a cell. Characters the mapping has no cell for and rows of
+    // differing lengths are reported with their position in the input.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> ParseResult<Self> {
+        Self::parse_in(input, input, cell)
+    }
+    // Like `parse`, for a grid that is only part of `input`, such that
+    // errors point into the whole input.
+    pub fn parse_in(
+        input: &str,
+        text: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Self> {
+        let (grid, []) = Self::parse_marked_in(input, text, [], cell)?;
+        Ok(grid)
+    }
+    // Like `parse`, also returning where each of the `markers` is found.
+    // Every marker has to appear exactly once, and is mapped to a cell
+    // like any other character.
+    pub fn parse_marked<const N: usize>(
+        input: &str,
+        markers: [char; N],
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<(Self, [UPoint; N])> {
+        Self::parse_marked_in(input, input, markers, cell)
+    }
+    fn parse_marked_in<const N: usize>(
+        input: &str,
+        text: &str,
+        markers: [char; N],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<(Self, [UPoint; N])> {
+        let mut vec = Vec::with_capacity(text.len());
+        let mut found = [None; N];
+        let mut width = None;
+        for (y, line) in text.split('\n').enumerate() {
+            let mut x = 0;
+            for (idx, c) in line.char_indices() {
+                let char_text = &line[idx..idx + c.len_utf8()];
+                if let Some(marker) = markers.iter().position(|&marker| marker == c) {
+                    if found[marker].replace(UPoint::new(x, y)).is_some() {
+                        let message = format!("expected only one {:?}", c);
+                        return Err(ParseError::new(input, char_text, message));
+                    }
+                }
+                let cell = cell(c)
+                    .ok_or_else(|| ParseError::new(input, char_text, "unexpected character"))?;
+                vec.push(cell);
+                x += 1;
+            }
+            match width {
+                _ if x == 0 => return Err(ParseError::new(input, line, "expected a row")),
+                Some(width) if x != width => {
+                    let message = format!("expected a row of {} cells, found {}", width, x);
+                    return Err(ParseError::new(input, line, message));
+                }
+                _ => width = Some(x),
+            }
+        }
+        let mut positions = [UPoint::new(0, 0); N];
+        for ((position, found), marker) in positions.iter_mut().zip(found).zip(markers) {
+            *position = found.ok_or_else(|| {
+                let end = &text[text.len()..];
+                ParseError::new(input, end, format!("expected {:?}", marker))
+            })?;
+        }
+        Ok((Self::from_vec(vec, width.unwrap_or(0)), positions))
+    }
     pub fn get(&self, loc: &UPoint) -> &T {
         &self.vec[loc.x + loc.y * self.width]
     }
@@ -200,12 +275,12 @@ impl<T> Grid<T> {
         self.width
     }
     pub fn height(&self) -> usize {
-        self.vec.len() / self.width
+        self.vec.len().checked_div(self.width).unwrap_or(0)
     }
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.vec.iter()
     }
-    pub fn iter_row(&self, row: usize) -> StepBy<Iter<T>> {
+    pub fn iter_row(&self, row: usize) -> StepBy<Iter<'_, T>> {
         if row >= self.height() {
             panic!(
                 "Tried to access row {} while the grid has {} rows",
@@ -218,7 +293,7 @@ impl<T> Grid<T> {
             .iter()
             .step_by(1)
     }
-    pub fn iter_col(&self, col: usize) -> StepBy<Iter<T>> {
+    pub fn iter_col(&self, col: usize) -> StepBy<Iter<'_, T>> {
         if col >= self.width() {
             panic!(
                 "Tried to access column {} while the grid has {} columns",
@@ -228,20 +303,21 @@ impl<T> Grid<T> {
         }
         self.vec[col..].iter().step_by(self.width)
     }
-    pub fn iter_rows(&self) -> GridRows<T> {
+    pub fn iter_rows(&self) -> GridRows<'_, T> {
         GridRows {
             grid: self,
             idx: 0,
             idx_back: self.height(),
         }
     }
-    pub fn iter_cols(&self) -> GridCols<T> {
+    pub fn iter_cols(&self) -> GridCols<'_, T> {
         GridCols {
             grid: self,
             idx: 0,
             idx_back: self.width(),
         }
     }
+    #[allow(dead_code)]
     pub fn transposed(&self) -> Self
     where
         T: Clone + Copy,
@@ -250,6 +326,135 @@ impl<T> Grid<T> {
         let width = self.height();
         Grid { vec, width }
     }
+    // Transposes the grid without allocating a new one, by moving every
+    // cell along the cycle of positions it is part of.
+    pub fn transpose(&mut self) {
+        let (width, height) = (self.width(), self.height());
+        let mut moved = vec![false; self.vec.len()];
+        for start in 0..self.vec.len() {
+            let mut idx = start;
+            while !moved[idx] {
+                moved[idx] = true;
+                let next = (idx % width) * height + idx / width;
+                if next == start {
+                    break;
+                }
+                self.vec.swap(start, next);
+                idx = next;
+            }
+        }
+        self.width = height;
+    }
+    // Mirrors the grid left to right.
+    pub fn flip_horizontal(&mut self) {
+        self.vec
+            .chunks_mut(self.width)
+            .for_each(|row| row.reverse());
+    }
+    // Mirrors the grid top to bottom.
+    #[allow(dead_code)]
+    pub fn flip_vertical(&mut self) {
+        // Reversing all cells flips both ways, so flip back horizontally.
+        self.vec.reverse();
+        self.flip_horizontal();
+    }
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+    #[allow(dead_code)]
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+    #[allow(dead_code)]
+    pub fn flipped_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        grid.flip_horizontal();
+        grid
+    }
+    #[allow(dead_code)]
+    pub fn flipped_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        grid.flip_vertical();
+        grid
+    }
+    #[allow(dead_code)]
+    pub fn rotated_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        grid.rotate_cw();
+        grid
+    }
+    #[allow(dead_code)]
+    pub fn rotated_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        grid.rotate_ccw();
+        grid
+    }
+    // Counts the cells that differ from their reflection in a horizontal
+    // mirror placed right above `row`. Rows reflected off the grid are
+    // not compared, so 0 means the grid is symmetric around the mirror.
+    pub fn row_mirror_diff(&self, row: usize) -> usize
+    where
+        T: PartialEq,
+    {
+        let above = self.iter_rows().rev().skip(self.height() - row).flatten();
+        let below = self.iter_rows().skip(row).flatten();
+        above.zip(below).filter(|(a, b)| a != b).count()
+    }
+    // Like `row_mirror_diff`, for a vertical mirror placed right before `col`.
+    pub fn col_mirror_diff(&self, col: usize) -> usize
+    where
+        T: PartialEq,
+    {
+        let left = self.iter_cols().rev().skip(self.width() - col).flatten();
+        let right = self.iter_cols().skip(col).flatten();
+        left.zip(right).filter(|(a, b)| a != b).count()
+    }
+    // Renders the grid as text, a line per row, with `cell` giving the
+    // character of every cell.
+    pub fn render(&self, cell: impl FnMut(&T) -> char) -> String {
+        self.render_overlaid(cell, [])
+    }
+    // Like `render`, with the characters of `overlay` drawn over the cells,
+    // such as a path to highlight.
+    pub fn render_overlaid(
+        &self,
+        cell: impl FnMut(&T) -> char,
+        overlay: impl IntoIterator<Item = (UPoint, char)>,
+    ) -> String {
+        let mut chars = Grid::from_vec(self.vec.iter().map(cell).collect(), self.width);
+        for (loc, c) in overlay {
+            *chars.get_mut(&loc) = c;
+        }
+        chars.to_string()
+    }
+    // Maps any point onto the grid, as if the grid repeated endlessly in
+    // every direction.
+    pub fn wrap(&self, loc: &IPoint) -> UPoint {
+        UPoint::new(
+            loc.x.rem_euclid(self.width() as isize) as usize,
+            loc.y.rem_euclid(self.height() as isize) as usize,
+        )
+    }
+    pub fn get_wrapped(&self, loc: &IPoint) -> &T {
+        self.get(&self.wrap(loc))
+    }
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
     pub fn as_vec(&self) -> &Vec<T> {
         &self.vec
     }
@@ -280,6 +485,74 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.iter_rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Renders points as text within the smallest bounds holding all of them,
+// with '.' where there are none. Later points are drawn over earlier ones,
+// so chaining a path after a set of points highlights it.
+#[allow(dead_code)]
+pub fn render_points<T: ToPrimitive>(points: impl IntoIterator<Item = (Point<T>, char)>) -> String {
+    let points = points
+        .into_iter()
+        .map(|(point, c)| {
+            let coord = |n: T| n.to_i64().expect("Point out of range to render");
+            ((coord(point.x), coord(point.y)), c)
+        })
+        .collect::<HashMap<(i64, i64), char>>();
+    let xs = || points.keys().map(|&(x, _)| x);
+    let ys = || points.keys().map(|&(_, y)| y);
+    let (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) =
+        (xs().min(), xs().max(), ys().min(), ys().max())
+    else {
+        return String::new();
+    };
+    (min_y..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| *points.get(&(x, y)).unwrap_or(&'.'))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// An endless view of a grid repeated in every direction, where the grid
+// itself is the tile at (0, 0).
+#[derive(Clone, Copy)]
+pub struct Tiled<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Tiled<'a, T> {
+    pub fn get(&self, loc: &IPoint) -> &'a T {
+        self.grid.get_wrapped(loc)
+    }
+    // Which repetition of the grid `loc` lies in.
+    pub fn tile(&self, loc: &IPoint) -> IPoint {
+        IPoint::new(
+            loc.x.div_euclid(self.grid.width() as isize),
+            loc.y.div_euclid(self.grid.height() as isize),
+        )
+    }
+    // Where `loc` lies within its tile.
+    #[allow(dead_code)]
+    pub fn wrap(&self, loc: &IPoint) -> UPoint {
+        self.grid.wrap(loc)
+    }
+}
+
 pub struct GridRows<'a, T> {
     grid: &'a Grid<T>,
     idx: usize,
@@ -351,3 +624,267 @@ impl<'a, T> DoubleEndedIterator for GridCols<'a, T> {
 }
 
 impl<'a, T> ExactSizeIterator for GridCols<'a, T> {}
+
+// A grid without bounds, storing only the cells that were set. The
+// bounds of the set cells are kept up to date as cells come and go.
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IPoint, T>,
+    bounds: Option<(IPoint, IPoint)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+    pub fn get(&self, loc: &IPoint) -> Option<&T> {
+        self.cells.get(loc)
+    }
+    #[allow(dead_code)]
+    pub fn get_mut(&mut self, loc: &IPoint) -> Option<&mut T> {
+        self.cells.get_mut(loc)
+    }
+    pub fn contains(&self, loc: &IPoint) -> bool {
+        self.cells.contains_key(loc)
+    }
+    pub fn insert(&mut self, loc: IPoint, cell: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                IPoint::new(min.x.min(loc.x), min.y.min(loc.y)),
+                IPoint::new(max.x.max(loc.x), max.y.max(loc.y)),
+            ),
+            None => (loc, loc),
+        });
+        self.cells.insert(loc, cell)
+    }
+    pub fn remove(&mut self, loc: &IPoint) -> Option<T> {
+        let cell = self.cells.remove(loc)?;
+        // Only cells on the edge of the bounds can shrink them.
+        if let Some((min, max)) = self.bounds {
+            if loc.x == min.x || loc.y == min.y || loc.x == max.x || loc.y == max.y {
+                self.bounds = Self::find_bounds(self.cells.keys());
+            }
+        }
+        Some(cell)
+    }
+    fn find_bounds<'a>(locs: impl Iterator<Item = &'a IPoint>) -> Option<(IPoint, IPoint)> {
+        locs.fold(None, |bounds, loc| match bounds {
+            Some((min, max)) => Some((
+                IPoint::new(min.x.min(loc.x), min.y.min(loc.y)),
+                IPoint::new(max.x.max(loc.x), max.y.max(loc.y)),
+            )),
+            None => Some((*loc, *loc)),
+        })
+    }
+    // The smallest and largest corner of the cells, if there are any.
+    pub fn bounds(&self) -> Option<(IPoint, IPoint)> {
+        self.bounds
+    }
+    // The number of points within the bounds, set or not.
+    pub fn area(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| {
+            (max.x - min.x + 1) as usize * (max.y - min.y + 1) as usize
+        })
+    }
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    pub fn count(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        self.cells.values().filter(|cell| pred(cell)).count()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&IPoint, &T)> {
+        self.cells.iter()
+    }
+    // The set cells among the 8 around `loc`.
+    pub fn neighbors_8(&self, loc: &IPoint) -> impl Iterator<Item = (IPoint, &T)> {
+        loc.neighbors_8()
+            .into_iter()
+            .filter_map(|loc| Some((loc, self.get(&loc)?)))
+    }
+    // The set cells among the 4 next to `loc`.
+    #[allow(dead_code)]
+    pub fn neighbors_4(&self, loc: &IPoint) -> impl Iterator<Item = (IPoint, &T)> {
+        loc.neighbors_4()
+            .into_iter()
+            .filter_map(|loc| Some((loc, self.get(&loc)?)))
+    }
+    // Copies the cells into a dense grid spanning the bounds, with `empty`
+    // for the cells that are not set. The smallest corner of the bounds
+    // ends up at (0, 0).
+    #[allow(dead_code)]
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return Grid::from_vec(Vec::new(), 0);
+        };
+        let width = (max.x - min.x + 1) as usize;
+        let mut grid = Grid::from_vec(vec![empty; self.area()], width);
+        for (loc, cell) in &self.cells {
+            let loc = UPoint::new((loc.x - min.x) as usize, (loc.y - min.y) as usize);
+            *grid.get_mut(&loc) = cell.clone();
+        }
+        grid
+    }
+}
+
+impl<T> FromIterator<(IPoint, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (IPoint, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (loc, cell) in iter {
+            grid.insert(loc, cell);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(c: char) -> Option<bool> {
+        match c {
+            '.' | 'S' | 'E' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parse_marked() {
+        let (grid, [start, end]) = Grid::parse_marked("S.#\n#.E", ['S', 'E'], cell).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(*grid.get(&UPoint::new(2, 0)));
+        assert_eq!((start, end), (UPoint::new(0, 0), UPoint::new(2, 1)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let err = Grid::parse_marked(input, ['S'], cell).err().unwrap();
+            (err.line, err.column, err.message)
+        };
+        assert_eq!(error("S.\n.x"), (2, 2, "unexpected character".to_string()));
+        assert_eq!(
+            error("S.\n..."),
+            (2, 1, "expected a row of 2 cells, found 3".to_string())
+        );
+        assert_eq!(error("S.\n.S"), (2, 2, "expected only one 'S'".to_string()));
+        assert_eq!(error("..\n.."), (2, 3, "expected 'S'".to_string()));
+    }
+
+    #[test]
+    fn parse_in() {
+        let input = "..\n\n.#\n#x";
+        let (_, text) = input.split_once("\n\n").unwrap();
+        let err = Grid::parse_in(input, text, cell).err().unwrap();
+        assert_eq!((err.line, err.column), (4, 2));
+    }
+
+    #[test]
+    fn render() {
+        let grid = Grid::parse("#..\n.#.", cell).unwrap();
+        assert_eq!(
+            grid.render(|&wall| if wall { '#' } else { '.' }),
+            "#..\n.#."
+        );
+        let path = [UPoint::new(1, 0), UPoint::new(2, 0)];
+        assert_eq!(
+            grid.render_overlaid(
+                |&wall| if wall { '#' } else { '.' },
+                path.iter().map(|&loc| (loc, 'O'))
+            ),
+            "#OO\n.#."
+        );
+        assert_eq!(Grid::from_vec(vec![1, 2, 3, 4], 2).to_string(), "12\n34");
+    }
+
+    #[test]
+    fn render_points() {
+        let points = [IPoint::new(-1, 0), IPoint::new(1, 1)];
+        let path = [IPoint::new(0, 1), IPoint::new(1, 1)];
+        let text = super::render_points(
+            points
+                .iter()
+                .map(|&point| (point, '#'))
+                .chain(path.iter().map(|&point| (point, '*'))),
+        );
+        assert_eq!(text, "#..\n.**");
+        assert_eq!(super::render_points::<usize>([]), "");
+    }
+
+    #[test]
+    fn rotate_and_flip() {
+        let grid = Grid::from_vec((1..=6).collect(), 3);
+        assert_eq!(grid.rotated_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotated_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.flipped_horizontal().to_string(), "321\n654");
+        assert_eq!(grid.flipped_vertical().to_string(), "456\n123");
+        let mut transposed = grid.clone();
+        transposed.transpose();
+        assert_eq!(transposed.to_string(), grid.transposed().to_string());
+        let mut rotated = grid.clone();
+        (0..4).for_each(|_| rotated.rotate_cw());
+        assert_eq!(rotated.to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn mirror_diff() {
+        let grid = Grid::parse("abba\nbaab\nbaac", Some).unwrap();
+        assert_eq!(grid.col_mirror_diff(2), 1);
+        assert_eq!(grid.col_mirror_diff(1), 3);
+        assert_eq!(grid.row_mirror_diff(2), 1);
+        assert_eq!(grid.transposed().row_mirror_diff(2), 1);
+    }
+
+    #[test]
+    fn sparse_grid() {
+        let mut grid = [(-1, 2), (3, -1), (0, 0)]
+            .into_iter()
+            .map(|(x, y)| (IPoint::new(x, y), '#'))
+            .collect::<SparseGrid<char>>();
+        assert_eq!(
+            grid.bounds(),
+            Some((IPoint::new(-1, -1), IPoint::new(3, 2)))
+        );
+        assert_eq!((grid.len(), grid.area()), (3, 20));
+        assert_eq!(grid.neighbors_8(&IPoint::new(-1, 1)).count(), 2);
+        assert_eq!(grid.neighbors_4(&IPoint::new(-1, 1)).count(), 1);
+        assert_eq!(grid.to_grid('.').to_string(), "....#\n.#...\n.....\n#....");
+        grid.remove(&IPoint::new(3, -1));
+        assert_eq!(grid.bounds(), Some((IPoint::new(-1, 0), IPoint::new(0, 2))));
+        grid.insert(IPoint::new(0, 1), 'o');
+        assert_eq!(grid.count(|&cell| cell == 'o'), 1);
+        grid.remove(&IPoint::new(-1, 2));
+        grid.remove(&IPoint::new(0, 0));
+        grid.remove(&IPoint::new(0, 1));
+        assert_eq!((grid.bounds(), grid.area()), (None, 0));
+        assert_eq!(grid.to_grid('.').to_string(), "");
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = Grid::from_vec((0..6).collect(), 3);
+        assert_eq!(*grid.get_wrapped(&IPoint::new(-1, 0)), 2);
+        assert_eq!(*grid.get_wrapped(&IPoint::new(4, -3)), 4);
+        let tiled = grid.tiled();
+        assert_eq!(*tiled.get(&IPoint::new(7, 5)), 4);
+        assert_eq!(tiled.tile(&IPoint::new(-1, 2)), IPoint::new(-1, 1));
+        assert_eq!(tiled.tile(&IPoint::new(2, 1)), IPoint::new(0, 0));
+        assert_eq!(tiled.wrap(&IPoint::new(-4, -1)), UPoint::new(2, 1));
+    }
+}
@@ -0,0 +1,342 @@
+use crate::solution::{DayReport, Timing};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+// Every benchmark run appends the timings of its stages here, one row
+// per stage, so that later runs can be compared against earlier ones.
+pub const HISTORY_PATH: &str = "bench_history.csv";
+const HEADER: &str = "run,commit,tag,input,year,day,stage,runs,min_ns,median_ns,mean_ns,max_ns";
+
+struct Row {
+    run: u128,
+    commit: String,
+    tag: String,
+    input: String,
+    year: usize,
+    day: String,
+    stage: String,
+    median: Duration,
+}
+
+pub struct Comparison {
+    pub year: usize,
+    pub day: String,
+    pub stage: String,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Comparison {
+    // How much slower the stage got, in percent.
+    pub fn change(&self) -> f64 {
+        if self.before.is_zero() {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+fn commit_hash() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// `input` tells which input was timed: "puzzles", "examples" or the path
+// given with `--input`, as only timings of the same input are compared.
+pub fn append(reports: &[DayReport], input: &str, tag: Option<&str>) -> io::Result<()> {
+    if input.contains([',', '\n']) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("the input path {:?} contains a comma or newline", input),
+        ));
+    }
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_millis());
+    let commit = commit_hash().unwrap_or_default();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_PATH)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for report in reports.iter().filter(|report| report.error.is_none()) {
+        for (stage, timing) in [
+            ("Parse", &report.parse_time),
+            ("Shared", &report.shared_time),
+            ("Part 1", &report.part_1.time),
+            ("Part 2", &report.part_2.time),
+        ] {
+            if timing.runs() == 0 {
+                continue;
+            }
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{}",
+                run,
+                commit,
+                tag.unwrap_or_default(),
+                input,
+                report.year,
+                report.label(),
+                stage,
+                csv_timing(timing)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn csv_timing(timing: &Timing) -> String {
+    format!(
+        "{},{},{},{},{}",
+        timing.runs(),
+        timing.min().as_nanos(),
+        timing.median().as_nanos(),
+        timing.mean().as_nanos(),
+        timing.max().as_nanos()
+    )
+}
+
+fn parse_row(line: &str) -> Option<Row> {
+    let fields = line.split(',').collect::<Vec<&str>>();
+    // Rows saved before the input was recorded can't be matched with any.
+    let (run, commit, tag, input, year, day, stage, median) = match fields[..] {
+        [run, commit, tag, input, year, day, stage, _, _, median, _, _] => {
+            (run, commit, tag, input, year, day, stage, median)
+        }
+        [run, commit, tag, year, day, stage, _, _, median, _, _] => {
+            (run, commit, tag, "", year, day, stage, median)
+        }
+        _ => return None,
+    };
+    Some(Row {
+        run: run.parse().ok()?,
+        commit: commit.to_string(),
+        tag: tag.to_string(),
+        input: input.to_string(),
+        year: year.parse().ok()?,
+        day: day.to_string(),
+        stage: stage.to_string(),
+        median: Duration::from_nanos(median.parse().ok()?),
+    })
+}
+
+fn read_history() -> io::Result<Vec<Row>> {
+    let text = fs::read_to_string(HISTORY_PATH).map_err(|err| match err.kind() {
+        ErrorKind::NotFound => io::Error::new(
+            ErrorKind::NotFound,
+            format!("no benchmark runs are saved in {} yet", HISTORY_PATH),
+        ),
+        _ => err,
+    })?;
+    text.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            parse_row(line).ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{} line {} is malformed", HISTORY_PATH, idx + 1),
+                )
+            })
+        })
+        .collect()
+}
+
+fn describe(row: &Row) -> String {
+    let commit = if row.commit.is_empty() {
+        "an unknown commit"
+    } else {
+        &row.commit
+    };
+    if row.tag.is_empty() {
+        format!("run {} at {}", row.run, commit)
+    } else {
+        format!("run {} ({}) at {}", row.run, row.tag, commit)
+    }
+}
+
+// Compares the latest run with the one before it that timed any of the
+// same stages, or with the latest earlier run tagged `baseline` or made
+// at a commit starting with it.
+// Returns a description of both runs along with the stages they share.
+pub fn compare(baseline: Option<&str>) -> io::Result<(String, String, Vec<Comparison>)> {
+    compare_rows(&read_history()?, baseline)
+}
+
+fn compare_rows(
+    rows: &[Row],
+    baseline: Option<&str>,
+) -> io::Result<(String, String, Vec<Comparison>)> {
+    let not_found = |message: String| io::Error::new(ErrorKind::NotFound, message);
+    let latest = rows
+        .last()
+        .ok_or_else(|| not_found(format!("{} has no runs", HISTORY_PATH)))?;
+    let same_stage = |row: &Row, other: &Row| {
+        !row.input.is_empty()
+            && (&row.input, row.year, &row.day, &row.stage)
+                == (&other.input, other.year, &other.day, &other.stage)
+    };
+    let latest_rows = rows
+        .iter()
+        .filter(|row| row.run == latest.run)
+        .collect::<Vec<&Row>>();
+    let earlier = rows.iter().rev().filter(|row| row.run != latest.run);
+    let before = match baseline {
+        Some(baseline) => earlier
+            .filter(|row| row.tag == baseline || row.commit.starts_with(baseline))
+            .map(|row| row.run)
+            .next()
+            .ok_or_else(|| not_found(format!("no earlier run is tagged or at {}", baseline)))?,
+        None => earlier
+            .filter(|row| latest_rows.iter().any(|latest| same_stage(row, latest)))
+            .map(|row| row.run)
+            .next()
+            .ok_or_else(|| not_found("no earlier run to compare against".to_string()))?,
+    };
+
+    let before_rows = rows
+        .iter()
+        .filter(|row| row.run == before)
+        .collect::<Vec<&Row>>();
+    let comparisons = latest_rows
+        .iter()
+        .filter_map(|after| {
+            let before = before_rows
+                .iter()
+                .find(|before| same_stage(before, after))?;
+            Some(Comparison {
+                year: after.year,
+                day: after.day.clone(),
+                stage: after.stage.clone(),
+                before: before.median,
+                after: after.median,
+            })
+        })
+        .collect();
+    Ok((describe(before_rows[0]), describe(latest), comparisons))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(lines: &[&str]) -> Vec<Row> {
+        lines.iter().map(|line| parse_row(line).unwrap()).collect()
+    }
+
+    fn compared(lines: &[&str], baseline: Option<&str>) -> (String, String, Vec<String>) {
+        let (before, after, comparisons) = compare_rows(&rows(lines), baseline).unwrap();
+        let comparisons = comparisons
+            .iter()
+            .map(|comparison| {
+                format!(
+                    "{} {} {}: {:?} -> {:?}",
+                    comparison.year,
+                    comparison.day,
+                    comparison.stage,
+                    comparison.before,
+                    comparison.after
+                )
+            })
+            .collect();
+        (before, after, comparisons)
+    }
+
+    #[test]
+    fn parse_rows() {
+        let row = parse_row("100,abc1234,fast,puzzles,2023,5,Part 1,3,10,20,25,40").unwrap();
+        assert_eq!(
+            (
+                row.run,
+                row.commit.as_str(),
+                row.tag.as_str(),
+                row.input.as_str()
+            ),
+            (100, "abc1234", "fast", "puzzles")
+        );
+        assert_eq!(
+            (row.year, row.day.as_str(), row.stage.as_str(), row.median),
+            (2023, "5", "Part 1", Duration::from_nanos(20))
+        );
+
+        // Rows from before the input was recorded have no input.
+        let row = parse_row("100,,,2022,1 (a),Parse,1,5,5,5,5").unwrap();
+        assert_eq!((row.commit.as_str(), row.tag.as_str()), ("", ""));
+        assert_eq!(
+            (
+                row.input.as_str(),
+                row.year,
+                row.day.as_str(),
+                row.stage.as_str()
+            ),
+            ("", 2022, "1 (a)", "Parse")
+        );
+
+        assert!(parse_row("100,,,2022,1,Parse,1,5,5").is_none());
+        assert!(parse_row("soon,,,puzzles,2022,1,Parse,1,5,5,5,5").is_none());
+    }
+
+    #[test]
+    fn compare_previous() {
+        let lines = [
+            "1,aaa,,puzzles,2023,5,Part 1,1,0,50,0,0",
+            "2,bbb,,puzzles,2023,6,Part 1,1,0,70,0,0",
+            "3,ccc,,examples,2023,5,Part 1,1,0,5,0,0",
+            "4,ddd,,puzzles,2023,5,Part 1,1,0,40,0,0",
+            "4,ddd,,puzzles,2023,5,Part 2,1,0,80,0,0",
+        ];
+        // Runs of other days or inputs are skipped.
+        assert_eq!(
+            compared(&lines, None),
+            (
+                "run 1 at aaa".to_string(),
+                "run 4 at ddd".to_string(),
+                vec!["2023 5 Part 1: 50ns -> 40ns".to_string()]
+            )
+        );
+
+        // Legacy rows don't know their input, so they are never matched.
+        let lines = [
+            "1,aaa,,2023,5,Part 1,1,0,50,0,0",
+            "2,,,puzzles,2023,5,Part 1,1,0,40,0,0",
+        ];
+        let err = compare_rows(&rows(&lines), None).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert_eq!(err.to_string(), "no earlier run to compare against");
+    }
+
+    #[test]
+    fn compare_baseline() {
+        let lines = [
+            "1,aaa1111,before,puzzles,2023,5,Part 1,1,0,50,0,0",
+            "2,bbb2222,,puzzles,2023,5,Part 1,1,0,45,0,0",
+            "3,ccc3333,,puzzles,2023,5,Part 1,1,0,40,0,0",
+        ];
+        assert_eq!(
+            compared(&lines, Some("before")).0,
+            "run 1 (before) at aaa1111"
+        );
+        assert_eq!(compared(&lines, Some("bbb")).0, "run 2 at bbb2222");
+        assert_eq!(compared(&lines, None).0, "run 2 at bbb2222");
+        // The latest run itself is never its own baseline.
+        let err = compare_rows(&rows(&lines), Some("ccc")).err().unwrap();
+        assert_eq!(err.to_string(), "no earlier run is tagged or at ccc");
+
+        let err = compare_rows(&[], None).err().unwrap();
+        assert_eq!(err.to_string(), "bench_history.csv has no runs");
+    }
+}
@@ -1,36 +1,348 @@
+mod answers;
+mod fetch;
 mod grids;
+mod history;
+mod parsing;
+mod report;
+mod runner;
+mod scaffold;
 mod solution;
+mod watch;
 mod year2022;
 mod year2023;
 mod year2024;
 
-use clap::Parser;
-use solution::Year;
-use year2022::Year2022;
-use year2023::Year2023;
-use year2024::Year2024;
+use clap::{builder::RangedU64ValueParser, Parser, Subcommand, ValueEnum};
+use solution::{input_path, DayEntry, DayReport, InputType, RunOptions};
+use std::{process, time::Duration};
+
+const YEARS: &[&[DayEntry]] = &[year2022::DAYS, year2023::DAYS, year2024::DAYS];
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Creates a new day from a template, registering it and its inputs.
+    New { year: usize, day: usize },
+    /// Downloads the puzzle input of a day, using the session token
+    /// from `AOC_SESSION` or `.aoc_session`.
+    Fetch {
+        year: usize,
+        day: usize,
+        /// Server to download from instead of `AOC_BASE_URL` or adventofcode.com.
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Downloads the input even if it is already there.
+        #[arg(short, long, action)]
+        force: bool,
+    },
+    /// Compares the timings of the latest benchmark run with an earlier one.
+    Compare {
+        /// Tag or commit of the run to compare with, instead of the previous run.
+        #[arg(short, long)]
+        baseline: Option<String>,
+        /// Percentage a stage may get slower by before it counts as a regression.
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Opts {
-    #[arg(required = true)]
-    day: usize,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(required_unless_present_any = ["all", "years", "list"])]
+    day: Option<usize>,
     #[arg(short, long, default_value = "2024")]
     year: usize,
+    /// Runs all examples of a day, or only the given lettered one (as `-e=a`).
+    #[arg(short, long, num_args = 0..=1, require_equals = true)]
+    example: Option<Option<String>>,
+    /// Checks the answers against the known ones, failing if any differ.
+    #[arg(short, long, action)]
+    check: bool,
+    /// Runs all days of the year.
+    #[arg(short, long, action)]
+    all: bool,
+    /// Runs all days of the given years, separated by commas.
+    #[arg(long, value_delimiter = ',')]
+    years: Vec<usize>,
+    /// Times every stage over the given number of runs.
+    #[arg(short, long)]
+    bench: Option<usize>,
+    /// Lists the implemented days.
+    #[arg(short, long, action)]
+    list: bool,
+    /// Reads the input from a file instead, or from stdin for `-`.
+    #[arg(short, long, conflicts_with_all = ["all", "years"])]
+    input: Option<String>,
+    /// Runs only the given part.
+    #[arg(short, long, value_parser = RangedU64ValueParser::<usize>::new().range(1..=2))]
+    part: Option<usize>,
+    /// Prints the results as text or as a JSON object per day.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Number of days to run at the same time.
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+    /// Runs both parts of a day at the same time.
+    #[arg(long, action)]
+    parallel_parts: bool,
+    /// Seconds after which a part is given up on and reported as a timeout.
+    /// With --bench, this is the time allowed for all runs of a part together.
+    #[arg(short, long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Records new answers to the answers file of the year, asking before
+    /// replacing ones that differ.
+    #[arg(short, long, action, conflicts_with_all = ["input", "params"])]
+    record: bool,
+    /// Names the timings of this benchmark run in the history.
+    #[arg(long, requires = "bench", value_parser = parse_tag)]
+    tag: Option<String>,
+    /// Trims the whitespace around every line of the input.
+    #[arg(long, action)]
+    trim_lines: bool,
+    /// Reports how the input was normalized before parsing.
     #[arg(short, long, action)]
-    example: bool,
+    verbose: bool,
+    /// Re-runs the day whenever its input or answers file changes.
+    #[arg(short, long, action, conflicts_with_all = ["all", "years"])]
+    watch: bool,
+    /// Overrides a parameter of the day, as `name=value`.
+    #[arg(
+        long = "param",
+        value_name = "NAME=VALUE",
+        value_parser = parse_param,
+        conflicts_with_all = ["all", "years"]
+    )]
+    params: Vec<(String, i64)>,
+}
+
+fn parse_param(text: &str) -> Result<(String, i64), String> {
+    let (name, value) = text
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, found {:?}", text))?;
+    let value = value
+        .replace('_', "")
+        .parse::<i64>()
+        .map_err(|err| format!("invalid value {:?}: {}", value, err))?;
+    Ok((name.to_string(), value))
+}
+
+fn parse_seconds(text: &str) -> Result<Duration, String> {
+    let seconds = text.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
+
+fn parse_tag(text: &str) -> Result<String, String> {
+    if text.contains([',', '\n']) {
+        return Err("tags cannot contain commas or newlines".to_string());
+    }
+    Ok(text.to_string())
+}
+
+fn days_of(year: usize) -> impl Iterator<Item = &'static DayEntry> {
+    YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .filter(move |entry| entry.year == year)
+}
+
+fn find_day(year: usize, day: usize) -> Option<&'static DayEntry> {
+    days_of(year).find(|entry| entry.day == day)
+}
+
+fn run_years(
+    years: &[usize],
+    opts: &RunOptions,
+    all_examples: bool,
+    jobs: usize,
+) -> Vec<DayReport> {
+    let entries = years
+        .iter()
+        .flat_map(|&year| days_of(year))
+        .collect::<Vec<&DayEntry>>();
+    runner::run_entries(&entries, opts, all_examples, jobs)
 }
 
 fn main() {
     let opts = Opts::parse();
-    let input_type = if opts.example {
-        solution::InputType::Examples
+    match opts.command {
+        Some(Command::New { year, day }) => {
+            if let Err(err) = scaffold::new_day(year, day) {
+                eprintln!("Could not create {} day {}: {}", year, day, err);
+                process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Fetch {
+            year,
+            day,
+            base_url,
+            force,
+        }) => {
+            let path = input_path(year, day, InputType::Puzzles, None);
+            match fetch::Fetcher::new(base_url).fetch(year, day, &path, force) {
+                Ok(true) => println!("Saved {}", path),
+                Ok(false) => println!("{} is already there", path),
+                Err(err) => {
+                    eprintln!("Could not fetch {} day {}: {}", year, day, err);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::Compare {
+            baseline,
+            threshold,
+        }) => match history::compare(baseline.as_deref()) {
+            Ok((before, after, comparisons)) => {
+                println!("Comparing {} with {}", after, before);
+                println!();
+                if report::print_comparison(&comparisons, threshold) > 0 {
+                    process::exit(1);
+                }
+                return;
+            }
+            Err(err) => {
+                eprintln!("Could not compare the timings: {}", err);
+                process::exit(1);
+            }
+        },
+        None => {}
+    }
+    if opts.list {
+        let entries = YEARS
+            .iter()
+            .flat_map(|days| days.iter())
+            .collect::<Vec<&DayEntry>>();
+        report::print_list(&entries);
+        return;
+    }
+    let input_type = if opts.example.is_some() {
+        InputType::Examples
     } else {
-        solution::InputType::Puzzles
+        InputType::Puzzles
     };
-    match opts.year {
-        2022 => Year2022.solve(opts.day, input_type),
-        2023 => Year2023.solve(opts.day, input_type),
-        2024 => Year2024.solve(opts.day, input_type),
-        _ => unimplemented!(),
+    if opts.watch && opts.input.as_deref() == Some("-") {
+        eprintln!("Cannot watch an input read from stdin");
+        process::exit(1);
+    }
+    let all_examples = matches!(opts.example, Some(None)) && opts.input.is_none();
+    let run_opts = RunOptions {
+        input_type,
+        example: opts.example.flatten(),
+        input: opts.input,
+        part: opts.part,
+        parallel_parts: opts.parallel_parts,
+        timeout: opts.timeout,
+        trim_lines: opts.trim_lines,
+        verbose: opts.verbose,
+        params: opts.params,
+        runs: opts.bench.unwrap_or(1),
+    };
+    let (reports, passed) = if opts.all || !opts.years.is_empty() {
+        let years = if opts.years.is_empty() {
+            vec![opts.year]
+        } else {
+            opts.years
+        };
+        let missing = years
+            .iter()
+            .filter(|&&year| days_of(year).next().is_none())
+            .collect::<Vec<&usize>>();
+        if !missing.is_empty() {
+            for year in missing {
+                eprintln!("No days of {} are implemented", year);
+            }
+            process::exit(1);
+        }
+        let reports = run_years(&years, &run_opts, all_examples, opts.jobs);
+        if opts.format == Format::Json {
+            report::print_json(&reports);
+        } else if opts.bench.is_some() {
+            report::print_bench(&reports);
+        } else {
+            report::print_table(&reports);
+        }
+        let passed = reports.iter().all(|report| report.passed());
+        (reports, passed)
+    } else {
+        let day = opts.day.unwrap();
+        let Some(entry) = find_day(opts.year, day) else {
+            eprintln!("{} day {} is not implemented", opts.year, day);
+            process::exit(1);
+        };
+        if let Some((name, _)) = run_opts
+            .params
+            .iter()
+            .find(|(name, _)| !entry.params.iter().any(|param| param.name == name))
+        {
+            let names = entry
+                .params
+                .iter()
+                .map(|param| param.name)
+                .collect::<Vec<&str>>();
+            eprintln!(
+                "{} day {} has no parameter {} (it has: {})",
+                opts.year,
+                day,
+                name,
+                if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(", ")
+                }
+            );
+            process::exit(1);
+        }
+        let reports = runner::run_entry(entry, &run_opts, all_examples);
+        if opts.format == Format::Json {
+            report::print_json(&reports);
+        } else {
+            for (idx, report) in reports.iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                report::print_day(report, opts.check);
+            }
+            if opts.bench.is_some() {
+                println!();
+                report::print_bench(&reports);
+            }
+        }
+        let passed = reports.iter().all(|report| {
+            report.error.is_none() && !report.timed_out() && (!opts.check || report.passed())
+        });
+        (reports, passed)
+    };
+    if opts.bench.is_some() {
+        let input = run_opts.input.as_deref().unwrap_or(input_type.dir());
+        if let Err(err) = history::append(&reports, input, opts.tag.as_deref()) {
+            eprintln!(
+                "Could not save the timings to {}: {}",
+                history::HISTORY_PATH,
+                err
+            );
+            process::exit(1);
+        }
+    }
+    if opts.record {
+        if let Err(err) = answers::record(&reports, input_type) {
+            eprintln!("Could not record the answers: {}", err);
+            process::exit(1);
+        }
+    }
+    if opts.watch {
+        let entry = find_day(opts.year, opts.day.unwrap()).unwrap();
+        watch::watch(entry, &run_opts, all_examples, reports);
+    }
+    if !passed {
+        process::exit(1);
     }
 }
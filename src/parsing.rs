@@ -0,0 +1,210 @@
+use std::{fmt::Display, str::FromStr};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+    line_text: String,
+}
+
+impl ParseError {
+    // The position is found from where `text` lies in `input`,
+    // so `text` should be a slice of `input` (as `split` and friends give).
+    pub fn new(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message: message.into(),
+            line_text: input[line_start..line_end].to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+        let marker_len = self
+            .text
+            .split('\n')
+            .next()
+            .map_or(0, |text| text.chars().count())
+            .max(1);
+        writeln!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.message, self.text
+        )?;
+        writeln!(f, "{} |", padding)?;
+        writeln!(f, "{} | {}", line_number, self.line_text.escape_debug())?;
+        write!(
+            f,
+            "{} | {}{}",
+            padding,
+            " ".repeat(self.column - 1),
+            "^".repeat(marker_len)
+        )
+    }
+}
+
+// Makes inputs uniform before they are parsed: CRLF line endings become
+// LF and trailing whitespace is stripped, so that splitting on '\n' gives
+// no empty last line. Each line can also be trimmed, for inputs pasted
+// with indentation. Returns the input along with what was changed.
+pub fn normalize(input: &str, trim_lines: bool) -> (String, Vec<String>) {
+    let mut changes = Vec::new();
+    let count = |n: usize, what: &str| format!("{} {}{}", n, what, if n == 1 { "" } else { "s" });
+    let crlf = input.matches("\r\n").count();
+    let mut input = input.replace("\r\n", "\n");
+    if crlf > 0 {
+        changes.push(format!("converted {}", count(crlf, "CRLF line ending")));
+    }
+    if trim_lines {
+        let mut trimmed = 0;
+        input = input
+            .split('\n')
+            .map(|line| {
+                let line_trimmed = line.trim();
+                if line_trimmed.len() < line.len() {
+                    trimmed += 1;
+                }
+                line_trimmed
+            })
+            .collect::<Vec<&str>>()
+            .join("\n");
+        if trimmed > 0 {
+            changes.push(format!("trimmed {}", count(trimmed, "line")));
+        }
+    }
+    let len = input.trim_end().len();
+    if len < input.len() {
+        let stripped = input[len..].chars().count();
+        changes.push(format!(
+            "stripped {} of trailing whitespace",
+            count(stripped, "character")
+        ));
+        input.truncate(len);
+    }
+    (input, changes)
+}
+
+pub fn split_once<'t>(
+    input: &str,
+    text: &'t str,
+    delimiter: &str,
+) -> ParseResult<(&'t str, &'t str)> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, text, format!("expected {:?}", delimiter)))
+}
+
+pub fn number<T>(input: &str, text: &str) -> ParseResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse::<T>()
+        .map_err(|err| ParseError::new(input, text, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_position() {
+        let input = "1 2\nçé x=1\n3";
+        let offset = input.find("x=1").unwrap();
+        let err = ParseError::new(input, &input[offset..offset + 3], "expected a number");
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected a number (found \"x=1\")\n\
+             \x20 |\n\
+             2 | çé x=1\n\
+             \x20 |    ^^^"
+        );
+
+        // Text from elsewhere can't be located, so it points at the start.
+        let err = ParseError::new(input, &String::from("x=1"), "expected a number");
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn normalize_input() {
+        assert_eq!(normalize("1\n2", false), ("1\n2".to_string(), vec![]));
+        assert_eq!(
+            normalize("1\n2\n", false),
+            (
+                "1\n2".to_string(),
+                vec!["stripped 1 character of trailing whitespace".to_string()]
+            )
+        );
+        assert_eq!(
+            normalize("1\r\n2\r\n\r\n", false),
+            (
+                "1\n2".to_string(),
+                vec![
+                    "converted 3 CRLF line endings".to_string(),
+                    "stripped 2 characters of trailing whitespace".to_string(),
+                ]
+            )
+        );
+        // Without `trim_lines`, only the end of the input is stripped.
+        assert_eq!(
+            normalize("  1 \n 2", false),
+            ("  1 \n 2".to_string(), vec![])
+        );
+        assert_eq!(
+            normalize("  1 \n2\n\t3", true),
+            ("1\n2\n3".to_string(), vec!["trimmed 2 lines".to_string()])
+        );
+        assert_eq!(
+            normalize("1\r\n 2\r\n", true),
+            (
+                "1\n2".to_string(),
+                vec![
+                    "converted 2 CRLF line endings".to_string(),
+                    "trimmed 1 line".to_string(),
+                    "stripped 1 character of trailing whitespace".to_string(),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn helper_errors() {
+        let input = "1-2\n".repeat(9) + "3+4";
+        let line = input.lines().last().unwrap();
+        let line = &input[input.len() - line.len()..];
+        let err = split_once(&input, line, "-").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 10, column 1: expected \"-\" (found \"3+4\")\n   |\n10 | 3+4\n   | ^^^"
+        );
+
+        let (a, b) = split_once(&input, &input[..3], "-").unwrap();
+        assert_eq!(number::<usize>(&input, a).unwrap(), 1);
+        let err = number::<u8>(&input, &input[1..2]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "-"));
+        assert_eq!(err.message, "invalid digit found in string");
+        assert_eq!(number::<usize>(&input, b).unwrap(), 2);
+
+        // Empty text still gets a marker.
+        let err = number::<usize>(&input, &input[3..3]).unwrap_err();
+        assert!(err.to_string().ends_with("1 | 1-2\n  |    ^"));
+    }
+}
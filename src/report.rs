@@ -0,0 +1,329 @@
+use crate::{
+    history::Comparison,
+    solution::{DayEntry, DayError, DayReport, PartReport, Status, Timing},
+};
+use std::time::Duration;
+
+pub fn print_day(report: &DayReport, check: bool) {
+    match &report.example {
+        Some(example) => println!("Title: {} (example {})", report.title, example),
+        None => println!("Title: {}", report.title),
+    }
+    if let Some(err) = &report.error {
+        print_error(report, err);
+    } else if check {
+        println!("Part 1: {}", report.part_1.status);
+        println!("Part 2: {}", report.part_2.status);
+    } else {
+        println!("Part 1: {}", day_answer(&report.part_1.status));
+        println!("Part 2: {}", day_answer(&report.part_2.status));
+    }
+}
+
+fn day_answer(status: &Status) -> &str {
+    match status {
+        Status::Timeout => status.label(),
+        _ => status.answer().unwrap_or("-"),
+    }
+}
+
+pub fn print_error(report: &DayReport, err: &DayError) {
+    eprintln!("{} day {}: {}", report.year, report.label(), err);
+}
+
+fn table_answer(answer: Option<&str>) -> String {
+    match answer {
+        // Multiline answers (such as CRT displays) do not fit in a table row.
+        Some(answer) if answer.contains('\n') => "<multiline>".to_string(),
+        Some(answer) => answer.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn compact_answer(status: &Status) -> String {
+    match status {
+        Status::Timeout => status.label().to_string(),
+        _ => table_answer(status.answer()),
+    }
+}
+
+// Prints the answers of every report next to the ones of the previous run.
+pub fn print_changes(before: &[DayReport], after: &[DayReport]) {
+    for report in after {
+        if let Some(err) = &report.error {
+            print_error(report, err);
+            continue;
+        }
+        let previous = before
+            .iter()
+            .find(|previous| previous.label() == report.label() && previous.error.is_none());
+        for (part, status, previous) in [
+            (1, &report.part_1.status, previous.map(|p| &p.part_1.status)),
+            (2, &report.part_2.status, previous.map(|p| &p.part_2.status)),
+        ] {
+            if matches!(status, Status::Skipped) {
+                continue;
+            }
+            let answer = compact_answer(status);
+            let change = match previous.map(compact_answer) {
+                Some(previous) if previous == answer => format!("{} (unchanged)", answer),
+                Some(previous) => format!("{} -> {}", previous, answer),
+                None => answer,
+            };
+            let check = match status {
+                Status::Fail(_, expected) => {
+                    format!("FAIL (expected {})", table_answer(Some(expected)))
+                }
+                Status::Timeout => String::new(),
+                _ => status.label().to_string(),
+            };
+            let line = format!(
+                "{} day {} part {}: {} {}",
+                report.year,
+                report.label(),
+                part,
+                change,
+                check
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+}
+
+fn print_rows(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths = header.iter().map(|cell| cell.len()).collect::<Vec<usize>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |row: &[String]| {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(
+        &header
+            .iter()
+            .map(|cell| cell.to_string())
+            .collect::<Vec<String>>(),
+    );
+    println!(
+        "{}",
+        "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1))
+    );
+    rows.iter().for_each(|row| print_row(row));
+}
+
+pub fn print_table(reports: &[DayReport]) {
+    let rows = reports
+        .iter()
+        .map(|report| {
+            vec![
+                report.year.to_string(),
+                report.label(),
+                report.title.clone(),
+                table_answer(report.part_1.status.answer()),
+                table_answer(report.part_2.status.answer()),
+                if let Some(err) = &report.error {
+                    err.label().to_string()
+                } else {
+                    format!(
+                        "{}/{}",
+                        report.part_1.status.label(),
+                        report.part_2.status.label()
+                    )
+                },
+                format!("{:.2?}", report.total_time()),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+    print_rows(
+        &["Year", "Day", "Title", "Part 1", "Part 2", "Check", "Time"],
+        &rows,
+    );
+
+    let total = reports
+        .iter()
+        .map(|report| report.total_time())
+        .sum::<Duration>();
+    let failed = reports.iter().filter(|report| !report.passed()).count();
+    println!();
+    println!(
+        "{} days, {} failed, total time {:.2?}",
+        reports.len(),
+        failed,
+        total
+    );
+}
+
+pub fn print_bench(reports: &[DayReport]) {
+    let timing_row = |report: &DayReport, stage: &str, timing: &Timing| {
+        vec![
+            report.year.to_string(),
+            report.label(),
+            stage.to_string(),
+            format!("{:.2?}", timing.min()),
+            format!("{:.2?}", timing.median()),
+            format!("{:.2?}", timing.mean()),
+            format!("{:.2?}", timing.max()),
+        ]
+    };
+    let rows = reports
+        .iter()
+        .flat_map(|report| {
+            [
+                ("Parse", &report.parse_time),
+                ("Shared", &report.shared_time),
+                ("Part 1", &report.part_1.time),
+                ("Part 2", &report.part_2.time),
+            ]
+            .into_iter()
+            // Skipped stages have nothing to report.
+            .filter(|(_, timing)| timing.runs() > 0)
+            .map(|(stage, timing)| timing_row(report, stage, timing))
+        })
+        .collect::<Vec<Vec<String>>>();
+    print_rows(
+        &["Year", "Day", "Stage", "Min", "Median", "Mean", "Max"],
+        &rows,
+    );
+
+    let runs = reports.first().map_or(0, |report| report.parse_time.runs());
+    let total = reports
+        .iter()
+        .map(|report| report.total_time())
+        .sum::<Duration>();
+    println!();
+    println!(
+        "{} days, {} runs each, total median time {:.2?}",
+        reports.len(),
+        runs,
+        total
+    );
+}
+
+// Prints how the median time of every stage changed, flagging the ones
+// that got slower by more than `threshold` percent. Returns how many did.
+pub fn print_comparison(comparisons: &[Comparison], threshold: f64) -> usize {
+    let rows = comparisons
+        .iter()
+        .map(|comparison| {
+            vec![
+                comparison.year.to_string(),
+                comparison.day.clone(),
+                comparison.stage.clone(),
+                format!("{:.2?}", comparison.before),
+                format!("{:.2?}", comparison.after),
+                format!("{:+.1}%", comparison.change()),
+                if comparison.change() > threshold {
+                    "REGRESSION".to_string()
+                } else {
+                    String::new()
+                },
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+    print_rows(
+        &["Year", "Day", "Stage", "Before", "After", "Change", ""],
+        &rows,
+    );
+
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.change() > threshold)
+        .count();
+    let faster = comparisons
+        .iter()
+        .filter(|comparison| comparison.change() < -threshold)
+        .count();
+    println!();
+    println!(
+        "{} stages, {} slower and {} faster by more than {}%",
+        comparisons.len(),
+        regressions,
+        faster,
+        threshold
+    );
+    regressions
+}
+
+pub fn print_list(entries: &[&DayEntry]) {
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.year.to_string(),
+                format!("{:02}", entry.day),
+                entry.title.to_string(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+    print_rows(&["Year", "Day", "Title"], &rows);
+}
+
+// Quotes text as a string, escaped the same way in JSON and TOML.
+pub fn quoted(text: &str) -> String {
+    let mut string = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            c if c.is_control() => string.push_str(&format!("\\u{:04x}", c as u32)),
+            c => string.push(c),
+        }
+    }
+    string.push('"');
+    string
+}
+
+fn json_option(text: Option<&str>) -> String {
+    text.map_or("null".to_string(), quoted)
+}
+
+fn json_timing(timing: &Timing) -> String {
+    format!(
+        "{{\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"max_ns\":{}}}",
+        timing.runs(),
+        timing.min().as_nanos(),
+        timing.median().as_nanos(),
+        timing.mean().as_nanos(),
+        timing.max().as_nanos()
+    )
+}
+
+fn json_part(part: &PartReport) -> String {
+    format!(
+        "{{\"answer\":{},\"expected\":{},\"status\":{},\"time\":{}}}",
+        json_option(part.status.answer()),
+        json_option(part.status.expected()),
+        quoted(part.status.label()),
+        json_timing(&part.time)
+    )
+}
+
+// Prints every report as a JSON object on its own line.
+pub fn print_json(reports: &[DayReport]) {
+    for report in reports {
+        println!(
+            "{{\"year\":{},\"day\":{},\"example\":{},\"title\":{},\"error\":{},\"parse_time\":{},\"shared_time\":{},\"part_1\":{},\"part_2\":{}}}",
+            report.year,
+            report.day,
+            json_option(report.example.as_deref()),
+            quoted(&report.title),
+            json_option(report.error.as_ref().map(|err| err.to_string()).as_deref()),
+            json_timing(&report.parse_time),
+            json_timing(&report.shared_time),
+            json_part(&report.part_1),
+            json_part(&report.part_2)
+        );
+    }
+}
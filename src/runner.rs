@@ -0,0 +1,72 @@
+use crate::{
+    report,
+    solution::{example_names, DayEntry, DayReport, RunOptions},
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+pub fn run_entry(entry: &DayEntry, opts: &RunOptions, all_examples: bool) -> Vec<DayReport> {
+    if !all_examples {
+        return vec![entry.run(opts)];
+    }
+    example_names(entry.year, entry.day)
+        .into_iter()
+        .map(|example| {
+            entry.run(&RunOptions {
+                example,
+                ..opts.clone()
+            })
+        })
+        .collect()
+}
+
+// Runs the days on a pool of `jobs` worker threads, reporting in the
+// order the days were given regardless of which finishes first.
+pub fn run_entries(
+    entries: &[&DayEntry],
+    opts: &RunOptions,
+    all_examples: bool,
+    jobs: usize,
+) -> Vec<DayReport> {
+    let next = &AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, entries.len().max(1)) {
+            let sender = sender.clone();
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(entry) = entries.get(idx) else {
+                    break;
+                };
+                let reports = run_entry(entry, opts, all_examples);
+                for report in &reports {
+                    if let Some(err) = &report.error {
+                        report::print_error(report, err);
+                    }
+                    eprintln!(
+                        "Ran {} day {} in {:.2?}",
+                        report.year,
+                        report.label(),
+                        report.total_time()
+                    );
+                }
+                sender.send((idx, reports)).unwrap();
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results = receiver
+        .into_iter()
+        .collect::<Vec<(usize, Vec<DayReport>)>>();
+    results.sort_by_key(|(idx, _)| *idx);
+    results
+        .into_iter()
+        .flat_map(|(_, reports)| reports)
+        .collect()
+}
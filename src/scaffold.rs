@@ -0,0 +1,325 @@
+use crate::solution::{input_path, InputType};
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+fn day_template(year: usize, day: usize) -> String {
+    format!(
+        "// https://adventofcode.com/{year}/day/{day}
+
+use crate::{{
+    parsing::ParseResult,
+    solution::{{Day, InputType}},
+}};
+
+pub struct Day{day:02} {{}}
+
+impl<'i> Day<'i> for Day{day:02} {{
+    type Part1Output = usize;
+    type Part2Output = usize;
+
+    const TITLE: &'static str = \"TODO\";
+
+    fn try_parse(_input: &'i str) -> ParseResult<Self> {{
+        Ok(Self {{}})
+    }}
+
+    fn solve_part_1(&self) -> Self::Part1Output {{
+        0
+    }}
+
+    fn solve_part_2(&self) -> Self::Part2Output {{
+        0
+    }}
+
+    fn solution(
+        &self,
+        input_type: InputType,
+    ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {{
+        match input_type {{
+            InputType::Examples => (None, None),
+            InputType::Puzzles => (None, None),
+        }}
+    }}
+}}
+"
+    )
+}
+
+fn entry_line(day: usize) -> String {
+    format!("    {} => day{:02}::Day{:02},", day, day, day)
+}
+
+fn module_line(day: usize) -> String {
+    format!("mod day{:02};", day)
+}
+
+fn entry_day(line: &str) -> Option<usize> {
+    line.trim().split_once(" => ")?.0.parse::<usize>().ok()
+}
+
+// Adds the day to the module declarations and the `days!` list of the
+// year, keeping both sorted.
+fn register_day(mod_rs: &str, day: usize) -> io::Result<String> {
+    let module = module_line(day);
+    let mut lines = mod_rs.lines().collect::<Vec<&str>>();
+    if lines
+        .iter()
+        .any(|line| entry_day(line) == Some(day) || *line == module)
+    {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("day {} is already registered", day),
+        ));
+    }
+    let mut idx = match lines
+        .iter()
+        .position(|line| entry_day(line).is_some_and(|other| other > day))
+    {
+        Some(idx) => idx,
+        None => lines
+            .iter()
+            .rposition(|line| *line == "}")
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "no days! list found"))?,
+    };
+    // Attributes belong to the entry below them.
+    while idx > 0 && lines[idx - 1].trim_start().starts_with("#[") {
+        idx -= 1;
+    }
+    let entry = entry_line(day);
+    lines.insert(idx, &entry);
+
+    let mod_idx = lines
+        .iter()
+        .position(|line| line.starts_with("mod day") && *line > module.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("mod day"))
+                .map(|idx| idx + 1)
+        });
+    match mod_idx {
+        Some(idx) => lines.insert(idx, &module),
+        None => {
+            lines.insert(0, "");
+            lines.insert(0, &module);
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds the `mod yearNNNN;` declaration and its `DAYS` to `main.rs`.
+fn register_year(main_rs: &str, year: usize) -> io::Result<String> {
+    let invalid = |msg: &str| io::Error::new(ErrorKind::InvalidData, msg.to_string());
+    let module = format!("year{}", year);
+
+    let mut lines = main_rs.lines().map(String::from).collect::<Vec<String>>();
+    let mod_lines = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("mod year"))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<usize>>();
+    let idx = mod_lines
+        .iter()
+        .find(|&&idx| lines[idx].as_str() > format!("mod {};", module).as_str())
+        .copied()
+        .or(mod_lines.last().map(|idx| idx + 1))
+        .ok_or_else(|| invalid("no year modules found"))?;
+    lines.insert(idx, format!("mod {};", module));
+    let main_rs = lines.join("\n") + "\n";
+
+    let start_marker = "const YEARS: &[&[DayEntry]] = &[";
+    let start = main_rs
+        .find(start_marker)
+        .ok_or_else(|| invalid("no YEARS table found"))?;
+    let end = start
+        + main_rs[start..]
+            .find("];")
+            .ok_or_else(|| invalid("unterminated YEARS table"))?;
+    let mut years = main_rs[start + start_marker.len()..end]
+        .split(',')
+        .map(|year| year.trim().to_string())
+        .filter(|year| !year.is_empty())
+        .collect::<Vec<String>>();
+    years.push(format!("{}::DAYS", module));
+    years.sort();
+
+    // Mirror how rustfmt lays out the table.
+    let single_line = format!("{}{}", start_marker, years.join(", "));
+    let table = if single_line.len() + 2 <= 100 {
+        single_line
+    } else {
+        format!(
+            "const YEARS: &[&[DayEntry]] = &[\n{}\n",
+            years
+                .iter()
+                .map(|year| format!("    {},", year))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    };
+    Ok(format!("{}{}{}", &main_rs[..start], table, &main_rs[end..]))
+}
+
+fn create_file(path: &str, contents: &str) -> io::Result<()> {
+    if Path::new(path).exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", path),
+        ));
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    println!("Created {}", path);
+    Ok(())
+}
+
+pub fn new_day(year: usize, day: usize) -> io::Result<()> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("day {} is not in 1..=25", day),
+        ));
+    }
+    let year_dir = format!("src/year{}", year);
+    let mod_path = format!("{}/mod.rs", year_dir);
+    let day_path = format!("{}/day{:02}.rs", year_dir, day);
+
+    if Path::new(&mod_path).exists() {
+        let mod_rs = register_day(&fs::read_to_string(&mod_path)?, day)?;
+        create_file(&day_path, &day_template(year, day))?;
+        fs::write(&mod_path, mod_rs)?;
+        println!("Registered day {} in {}", day, mod_path);
+    } else {
+        let main_rs = register_year(&fs::read_to_string("src/main.rs")?, year)?;
+        create_file(&day_path, &day_template(year, day))?;
+        create_file(
+            &mod_path,
+            &format!(
+                "{}\n\ncrate::solution::days! {{\n    {};\n{}\n}}\n",
+                module_line(day),
+                year,
+                entry_line(day)
+            ),
+        )?;
+        fs::write("src/main.rs", main_rs)?;
+        println!("Registered year {} in src/main.rs", year);
+    }
+
+    for input_type in [InputType::Examples, InputType::Puzzles] {
+        let path = input_path(year, day, input_type, None);
+        if !Path::new(&path).exists() {
+            create_file(&path, "")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "\
+mod day01;
+mod day05;
+mod day22;
+
+crate::solution::days! {
+    2023;
+    1 => day01::Day01,
+    #[ignore = \"slow\"]
+    5 => day05::Day05,
+    22 => day22::Day22 (shared),
+}
+";
+
+    const MAIN_RS: &str = "\
+mod scaffold;
+mod year2022;
+mod year2023;
+mod year2024;
+
+const YEARS: &[&[DayEntry]] = &[year2022::DAYS, year2023::DAYS, year2024::DAYS];
+
+fn main() {}
+";
+
+    #[test]
+    fn register_day_sorted() {
+        assert_eq!(
+            register_day(MOD_RS, 3).unwrap(),
+            MOD_RS
+                .replace("mod day05;", "mod day03;\nmod day05;")
+                .replace("    #[ignore", "    3 => day03::Day03,\n    #[ignore")
+        );
+        assert_eq!(
+            register_day(MOD_RS, 10).unwrap(),
+            MOD_RS
+                .replace("mod day22;", "mod day10;\nmod day22;")
+                .replace("    22 =>", "    10 => day10::Day10,\n    22 =>")
+        );
+        assert_eq!(
+            register_day(MOD_RS, 25).unwrap(),
+            MOD_RS
+                .replace("mod day22;", "mod day22;\nmod day25;")
+                .replace("(shared),\n", "(shared),\n    25 => day25::Day25,\n")
+        );
+        assert_eq!(
+            register_day("crate::solution::days! {\n    2025;\n}\n", 1).unwrap(),
+            "mod day01;\n\ncrate::solution::days! {\n    2025;\n    1 => day01::Day01,\n}\n"
+        );
+    }
+
+    #[test]
+    fn register_day_errors() {
+        for day in [1, 5, 22] {
+            let err = register_day(MOD_RS, day).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+            assert_eq!(
+                err.to_string(),
+                format!("day {} is already registered", day)
+            );
+        }
+        // A declared module counts as registered, even without an entry.
+        let err = register_day("mod day02;\n", 2).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        let err = register_day("mod day01;\n", 2).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn register_year_sorted() {
+        let main_rs = register_year(MAIN_RS, 2021).unwrap();
+        assert!(main_rs.contains("mod scaffold;\nmod year2021;\nmod year2022;\n"));
+        assert!(main_rs.contains(
+            "const YEARS: &[&[DayEntry]] = &[year2021::DAYS, year2022::DAYS, year2023::DAYS, year2024::DAYS];\n"
+        ));
+
+        // A fifth year no longer fits in 100 columns, so rustfmt puts
+        // every year on its own line.
+        let main_rs = register_year(&main_rs, 2025).unwrap();
+        assert!(main_rs.contains("mod year2024;\nmod year2025;\n"));
+        let table = "\
+const YEARS: &[&[DayEntry]] = &[
+    year2021::DAYS,
+    year2022::DAYS,
+    year2023::DAYS,
+    year2024::DAYS,
+    year2025::DAYS,
+];
+";
+        assert!(main_rs.contains(table));
+        // The layout is kept when yet another year is added.
+        let main_rs = register_year(&main_rs, 2020).unwrap();
+        assert!(main_rs.contains("mod year2020;\nmod year2021;\n"));
+        assert!(main_rs.contains(&table.replace("[\n", "[\n    year2020::DAYS,\n")));
+
+        let err = register_year("fn main() {}\n", 2025).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -1,58 +1,742 @@
-use std::{fmt::Display, fs};
+use crate::{
+    answers::{answers_path, Answers},
+    parsing::{normalize, ParseError, ParseResult},
+};
+use std::{
+    fmt::Display,
+    fs, io, panic,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
 
 pub trait Day<'i> {
     type Part1Output: Display;
     type Part2Output: Display;
 
-    fn title(&self) -> &str {
-        "Title Unknown"
-    }
+    const TITLE: &'static str = "Title Unknown";
 
-    fn parse(input: &'i str) -> Self
+    // Malformed input is reported as a `ParseError` where the day checks
+    // for it, instead of panicking.
+    fn try_parse(input: &'i str) -> ParseResult<Self>
     where
         Self: Sized;
 
+    // Named parameters of the day (such as the row to look at in 2022
+    // day15), given to `set_params` after parsing. `--param` overrides
+    // their defaults, so values the day can't use are reported as errors.
+    const PARAMS: &'static [Param] = &[];
+    fn set_params(&mut self, _params: &Params) -> Result<(), String> {
+        Ok(())
+    }
+
     fn solve_part_1(&self) -> Self::Part1Output;
     fn solve_part_2(&self) -> Self::Part2Output;
 
     fn solution(
         &self,
-        input_type: InputType,
+        _input_type: InputType,
     ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
         (None, None)
     }
 
-    fn solve(&self) {
-        println!("Title: {}", self.title());
-        println!("Part 1: {}", self.solve_part_1());
-        println!("Part 2: {}", self.solve_part_2());
+    // Answers to the lettered examples (`dayNN-<name>.txt`), which can
+    // leave out the parts they do not apply to.
+    fn example_solution(
+        &self,
+        _name: &str,
+    ) -> (Expected<Self::Part1Output>, Expected<Self::Part2Output>) {
+        (Expected::Unknown, Expected::Unknown)
+    }
+}
+
+// Days whose parts build on the same work (such as settling the slabs
+// in 2023 day22) can do it once in `shared`, and have `solve_part_1`
+// and `solve_part_2` call the `_with` variants with its result. They
+// are registered as `N => dayNN::DayNN (shared)` so that the runner
+// times `shared` on its own instead of as part of either part.
+pub trait SharedDay<'i>: Day<'i> {
+    type Shared;
+
+    fn shared(&self) -> Self::Shared;
+    fn solve_part_1_with(&self, shared: &Self::Shared) -> Self::Part1Output;
+    fn solve_part_2_with(&self, shared: &Self::Shared) -> Self::Part2Output;
+}
+
+pub struct Param {
+    pub name: &'static str,
+    pub examples: i64,
+    pub puzzles: i64,
+}
+
+pub struct Params(Vec<(&'static str, i64)>);
+
+impl Params {
+    // Takes the defaults of the input type, unless overridden.
+    fn resolve(
+        params: &'static [Param],
+        input_type: InputType,
+        overrides: &[(String, i64)],
+    ) -> Self {
+        Self(
+            params
+                .iter()
+                .map(|param| {
+                    let value = overrides
+                        .iter()
+                        .rev()
+                        .find(|(name, _)| name == param.name)
+                        .map_or(
+                            match input_type {
+                                InputType::Examples => param.examples,
+                                InputType::Puzzles => param.puzzles,
+                            },
+                            |(_, value)| *value,
+                        );
+                    (param.name, value)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, String> {
+        let (_, value) = self
+            .0
+            .iter()
+            .find(|(param, _)| *param == name)
+            .unwrap_or_else(|| panic!("parameter {} is not declared", name));
+        T::try_from(*value).map_err(|_| format!("{} can't be {}", name, value))
+    }
+}
+
+pub enum Expected<T> {
+    Answer(T),
+    Unknown,
+    NotApplicable,
+}
+
+impl<T> From<Option<T>> for Expected<T> {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Self::Unknown, Self::Answer)
+    }
+}
+
+impl<T: Display> Expected<T> {
+    fn to_text(&self) -> Expected<String> {
+        match self {
+            Self::Answer(answer) => Expected::Answer(answer.to_string()),
+            Self::Unknown => Expected::Unknown,
+            Self::NotApplicable => Expected::NotApplicable,
+        }
+    }
+}
+
+// Answers recorded in the answers file of the year take precedence
+// over the ones the day gives itself.
+fn expected_answers<'i, D: Day<'i>>(
+    solution: &D,
+    answers: &Answers,
+    day: usize,
+    input_type: InputType,
+    example: Option<&str>,
+) -> (Expected<String>, Expected<String>) {
+    let (expected_1, expected_2) = match example {
+        Some(example) => {
+            let (expected_1, expected_2) = solution.example_solution(example);
+            (expected_1.to_text(), expected_2.to_text())
+        }
+        None => {
+            let (expected_1, expected_2) = solution.solution(input_type);
+            (
+                Expected::from(expected_1).to_text(),
+                Expected::from(expected_2).to_text(),
+            )
+        }
+    };
+    let recorded = |part, expected| match answers.get(day, input_type, example, part) {
+        Some(answer) => Expected::Answer(answer.to_string()),
+        None => expected,
+    };
+    (recorded(1, expected_1), recorded(2, expected_2))
+}
+
+pub enum Status {
+    Pass(String),
+    Fail(String, String),
+    Unknown(String),
+    Skipped,
+    Timeout,
+}
+
+impl Status {
+    pub fn of<T: Display>(answer: &T, expected: Option<String>) -> Self {
+        let answer = answer.to_string();
+        match expected {
+            Some(expected) if expected == answer => Self::Pass(answer),
+            Some(expected) => Self::Fail(answer, expected),
+            None => Self::Unknown(answer),
+        }
+    }
+    pub fn passed(&self) -> bool {
+        !matches!(self, Self::Fail(..) | Self::Timeout)
+    }
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Self::Pass(answer) | Self::Fail(answer, _) | Self::Unknown(answer) => Some(answer),
+            Self::Skipped | Self::Timeout => None,
+        }
+    }
+    pub fn expected(&self) -> Option<&str> {
+        match self {
+            Self::Pass(expected) | Self::Fail(_, expected) => Some(expected),
+            Self::Unknown(_) | Self::Skipped | Self::Timeout => None,
+        }
+    }
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Pass(_) => "PASS",
+            Self::Fail(..) => "FAIL",
+            Self::Unknown(_) => "UNKNOWN",
+            Self::Skipped => "SKIPPED",
+            Self::Timeout => "TIMEOUT",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Multiline answers (such as CRT displays) start on their own line.
+        let fmt_answer = |answer: &str| {
+            if answer.contains('\n') {
+                format!("\n{}", answer.trim_end())
+            } else {
+                answer.to_string()
+            }
+        };
+        match self {
+            Self::Fail(answer, expected) => write!(
+                f,
+                "FAIL {} (expected {})",
+                fmt_answer(answer),
+                fmt_answer(expected)
+            ),
+            Self::Skipped | Self::Timeout => write!(f, "{}", self.label()),
+            _ => write!(f, "{} {}", self.label(), fmt_answer(self.answer().unwrap())),
+        }
+    }
+}
+
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Self) {
+        let mut samples = Vec::with_capacity(runs);
+        let mut result = None;
+        for _ in 0..runs.max(1) {
+            let start = Instant::now();
+            result = Some(f());
+            samples.push(start.elapsed());
+        }
+        samples.sort();
+        (result.unwrap(), Self { samples })
+    }
+    fn none() -> Self {
+        Self {
+            samples: Vec::new(),
+        }
+    }
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+    pub fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+    pub fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+        if self.samples.is_empty() {
+            Duration::ZERO
+        } else if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+}
+
+pub struct PartReport {
+    pub status: Status,
+    pub time: Timing,
+}
+
+impl PartReport {
+    fn skipped() -> Self {
+        Self {
+            status: Status::Skipped,
+            time: Timing::none(),
+        }
+    }
+}
+
+pub enum DayError {
+    Input(String, io::Error),
+    Parse(ParseError),
+    Param(String),
+}
+
+impl DayError {
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Input(..) => "INPUT ERROR",
+            Self::Parse(_) => "PARSE ERROR",
+            Self::Param(_) => "PARAM ERROR",
+        }
+    }
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(path, err) => write!(f, "Failed to read {}: {}", path, err),
+            Self::Parse(err) => write!(f, "Failed to parse the input at {}", err),
+            Self::Param(err) => write!(f, "Invalid parameter: {}", err),
+        }
+    }
+}
+
+pub struct DayReport {
+    pub year: usize,
+    pub day: usize,
+    pub example: Option<String>,
+    pub title: String,
+    pub parse_time: Timing,
+    pub shared_time: Timing,
+    pub error: Option<DayError>,
+    pub part_1: PartReport,
+    pub part_2: PartReport,
+}
+
+impl DayReport {
+    fn failed(
+        year: usize,
+        day: usize,
+        title: &str,
+        opts: &RunOptions,
+        parse_time: Timing,
+        error: DayError,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            example: opts.example.clone(),
+            title: title.to_string(),
+            parse_time,
+            shared_time: Timing::none(),
+            error: Some(error),
+            part_1: PartReport::skipped(),
+            part_2: PartReport::skipped(),
+        }
+    }
+    pub fn label(&self) -> String {
+        match &self.example {
+            Some(example) => format!("{:02}-{}", self.day, example),
+            None => format!("{:02}", self.day),
+        }
+    }
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.part_1.status.passed() && self.part_2.status.passed()
+    }
+    pub fn timed_out(&self) -> bool {
+        matches!(self.part_1.status, Status::Timeout)
+            || matches!(self.part_2.status, Status::Timeout)
+    }
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.median()
+            + self.shared_time.median()
+            + self.part_1.time.median()
+            + self.part_2.time.median()
+    }
+}
+
+#[derive(Clone)]
+pub struct RunOptions {
+    pub input_type: InputType,
+    // Name of the lettered example to read instead of `dayNN.txt`.
+    pub example: Option<String>,
+    // Path to read the input from instead of the inputs directory,
+    // where "-" reads it from stdin.
+    pub input: Option<String>,
+    // Only runs the given part when set.
+    pub part: Option<usize>,
+    // Runs both parts at the same time, each on its own thread.
+    pub parallel_parts: bool,
+    // Gives up on a part whose runs take longer than this altogether,
+    // reporting it as a timeout.
+    pub timeout: Option<Duration>,
+    // Trims every line of the input, on top of the normalization all inputs get.
+    pub trim_lines: bool,
+    // Reports what the normalization of the input changed.
+    pub verbose: bool,
+    // Overrides of the parameters of the day, by name.
+    pub params: Vec<(String, i64)>,
+    pub runs: usize,
+}
+
+impl RunOptions {
+    fn runs_part(&self, part: usize) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+fn run_part<T: Display>(
+    runs: usize,
+    expected: Expected<String>,
+    solve: impl FnMut() -> T,
+) -> PartReport {
+    let expected = match expected {
+        Expected::Answer(answer) => Some(answer),
+        Expected::Unknown => None,
+        Expected::NotApplicable => return PartReport::skipped(),
+    };
+    let (answer, time) = Timing::measure(runs, solve);
+    PartReport {
+        status: Status::of(&answer, expected),
+        time,
+    }
+}
+
+struct PendingPart<T> {
+    started: Instant,
+    handle: JoinHandle<()>,
+    receiver: Receiver<(T, Timing)>,
+}
+
+impl<T: Send + 'static> PendingPart<T> {
+    fn spawn(solve: impl FnOnce() -> (T, Timing) + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            // The receiver is gone if the part timed out.
+            let _ = sender.send(solve());
+        });
+        Self {
+            started: Instant::now(),
+            handle,
+            receiver,
+        }
+    }
+
+    // Threads cannot be stopped, so a part that times out is left running
+    // in the background until the process exits.
+    fn wait(self, timeout: Duration) -> Option<(T, Timing)> {
+        match self
+            .receiver
+            .recv_timeout(timeout.saturating_sub(self.started.elapsed()))
+        {
+            Ok(result) => Some(result),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => {
+                panic::resume_unwind(self.handle.join().unwrap_err())
+            }
+        }
     }
 }
 
-pub struct NoSolution;
+fn wait_part<T: Display + Send + 'static>(
+    expected: Expected<String>,
+    pending: Option<PendingPart<T>>,
+    timeout: Duration,
+) -> PartReport {
+    let Some(pending) = pending else {
+        return PartReport::skipped();
+    };
+    let expected = match expected {
+        Expected::Answer(answer) => Some(answer),
+        Expected::Unknown | Expected::NotApplicable => None,
+    };
+    match pending.wait(timeout) {
+        Some((answer, time)) => PartReport {
+            status: Status::of(&answer, expected),
+            time,
+        },
+        None => PartReport {
+            status: Status::Timeout,
+            time: Timing::none(),
+        },
+    }
+}
 
-impl<'i> Day<'i> for NoSolution {
-    type Part1Output = usize;
-    type Part2Output = usize;
+// How a day is run once parsed, where `shared` is the work both parts
+// build on. It is done once before them and timed as its own stage.
+pub struct Stages<'i, D: Day<'i>, S> {
+    shared: fn(&D, usize) -> (S, Timing),
+    part_1: fn(&D, &S) -> D::Part1Output,
+    part_2: fn(&D, &S) -> D::Part2Output,
+}
 
-    fn parse(_input: &'i str) -> Self {
-        Self
+pub fn stages<'i, D: Day<'i>>() -> Stages<'i, D, ()> {
+    Stages {
+        shared: |_, _| ((), Timing::none()),
+        part_1: |solution, _| solution.solve_part_1(),
+        part_2: |solution, _| solution.solve_part_2(),
     }
-    fn solve_part_1(&self) -> Self::Part1Output {
-        0
+}
+
+pub fn shared_stages<'i, D: SharedDay<'i>>() -> Stages<'i, D, D::Shared> {
+    Stages {
+        shared: |solution, runs| Timing::measure(runs, || solution.shared()),
+        part_1: D::solve_part_1_with,
+        part_2: D::solve_part_2_with,
     }
-    fn solve_part_2(&self) -> Self::Part2Output {
-        0
+}
+
+fn timed_out<T>(expected: &Expected<T>) -> PartReport {
+    match expected {
+        Expected::NotApplicable => PartReport::skipped(),
+        _ => PartReport {
+            status: Status::Timeout,
+            time: Timing::none(),
+        },
     }
 }
 
+fn run_parts<'i, D, S>(
+    solution: D,
+    stages: Stages<'i, D, S>,
+    expected_1: Expected<String>,
+    expected_2: Expected<String>,
+    opts: &RunOptions,
+) -> (Timing, PartReport, PartReport)
+where
+    D: Day<'i> + Sync,
+    D::Part1Output: Send,
+    S: Sync,
+{
+    let (shared, shared_time) = (stages.shared)(&solution, opts.runs);
+    let solve_1 = || (stages.part_1)(&solution, &shared);
+    let solve_2 = || (stages.part_2)(&solution, &shared);
+    let (part_1, part_2) = if opts.parallel_parts {
+        thread::scope(|scope| {
+            let part_1 = scope.spawn(|| run_part(opts.runs, expected_1, solve_1));
+            let part_2 = run_part(opts.runs, expected_2, solve_2);
+            let part_1 = part_1
+                .join()
+                .unwrap_or_else(|err| panic::resume_unwind(err));
+            (part_1, part_2)
+        })
+    } else {
+        (
+            run_part(opts.runs, expected_1, solve_1),
+            run_part(opts.runs, expected_2, solve_2),
+        )
+    };
+    (shared_time, part_1, part_2)
+}
+
+fn run_parts_with_timeout<D, S>(
+    solution: D,
+    stages: Stages<'static, D, S>,
+    expected_1: Expected<String>,
+    expected_2: Expected<String>,
+    opts: &RunOptions,
+    timeout: Duration,
+) -> (Timing, PartReport, PartReport)
+where
+    D: Day<'static> + Send + Sync + 'static,
+    D::Part1Output: Send + 'static,
+    D::Part2Output: Send + 'static,
+    S: Send + Sync + 'static,
+{
+    let runs = opts.runs;
+    let Stages {
+        shared,
+        part_1,
+        part_2,
+    } = stages;
+    let solution = Arc::new(solution);
+    let pending = {
+        let solution = Arc::clone(&solution);
+        PendingPart::spawn(move || shared(&solution, runs))
+    };
+    let Some((shared, shared_time)) = pending.wait(timeout) else {
+        return (
+            Timing::none(),
+            timed_out(&expected_1),
+            timed_out(&expected_2),
+        );
+    };
+    let shared = Arc::new(shared);
+    let spawn_1 = || {
+        let (solution, shared) = (Arc::clone(&solution), Arc::clone(&shared));
+        PendingPart::spawn(move || Timing::measure(runs, || part_1(&solution, &shared)))
+    };
+    let spawn_2 = || {
+        let (solution, shared) = (Arc::clone(&solution), Arc::clone(&shared));
+        PendingPart::spawn(move || Timing::measure(runs, || part_2(&solution, &shared)))
+    };
+    let applies_1 = !matches!(expected_1, Expected::NotApplicable);
+    let applies_2 = !matches!(expected_2, Expected::NotApplicable);
+
+    let pending_1 = applies_1.then(spawn_1);
+    let pending_2 = (applies_2 && opts.parallel_parts).then(spawn_2);
+    let part_1 = wait_part(expected_1, pending_1, timeout);
+    let pending_2 = pending_2.or_else(|| applies_2.then(spawn_2));
+    let part_2 = wait_part(expected_2, pending_2, timeout);
+    (shared_time, part_1, part_2)
+}
+
+// Parses the input and runs the parts of the day with `run_parts`.
+fn run_stages<'i, D, S>(
+    year: usize,
+    day: usize,
+    input: &'i str,
+    opts: &RunOptions,
+    stages: Stages<'i, D, S>,
+    run_parts: impl FnOnce(
+        D,
+        Stages<'i, D, S>,
+        Expected<String>,
+        Expected<String>,
+    ) -> (Timing, PartReport, PartReport),
+) -> DayReport
+where
+    D: Day<'i>,
+{
+    let (parsed, parse_time) = Timing::measure(opts.runs, || D::try_parse(input));
+    let mut solution = match parsed {
+        Ok(solution) => solution,
+        Err(err) => {
+            return DayReport::failed(year, day, D::TITLE, opts, parse_time, DayError::Parse(err))
+        }
+    };
+    if let Err(err) =
+        solution.set_params(&Params::resolve(D::PARAMS, opts.input_type, &opts.params))
+    {
+        return DayReport::failed(year, day, D::TITLE, opts, parse_time, DayError::Param(err));
+    }
+    // Custom inputs and overridden parameters have no known answers.
+    let (expected_1, expected_2) = if opts.input.is_some() || !opts.params.is_empty() {
+        (Expected::Unknown, Expected::Unknown)
+    } else {
+        match Answers::load(year) {
+            Ok(answers) => expected_answers(
+                &solution,
+                &answers,
+                day,
+                opts.input_type,
+                opts.example.as_deref(),
+            ),
+            Err(err) => {
+                let error = DayError::Input(answers_path(year), err);
+                return DayReport::failed(year, day, D::TITLE, opts, parse_time, error);
+            }
+        }
+    };
+    let expected_1 = if opts.runs_part(1) {
+        expected_1
+    } else {
+        Expected::NotApplicable
+    };
+    let expected_2 = if opts.runs_part(2) {
+        expected_2
+    } else {
+        Expected::NotApplicable
+    };
+    let (shared_time, part_1, part_2) = if matches!(
+        (&expected_1, &expected_2),
+        (Expected::NotApplicable, Expected::NotApplicable)
+    ) {
+        (Timing::none(), PartReport::skipped(), PartReport::skipped())
+    } else {
+        run_parts(solution, stages, expected_1, expected_2)
+    };
+    DayReport {
+        year,
+        day,
+        example: opts.example.clone(),
+        title: D::TITLE.to_string(),
+        parse_time,
+        shared_time,
+        error: None,
+        part_1,
+        part_2,
+    }
+}
+
+// Runs a day on an input borrowed only for the run.
+pub fn run<'i, D, S>(
+    year: usize,
+    day: usize,
+    input: &'i str,
+    opts: &RunOptions,
+    stages: Stages<'i, D, S>,
+) -> DayReport
+where
+    D: Day<'i> + Sync,
+    D::Part1Output: Send,
+    S: Sync,
+{
+    run_stages(
+        year,
+        day,
+        input,
+        opts,
+        stages,
+        |solution, stages, expected_1, expected_2| {
+            run_parts(solution, stages, expected_1, expected_2, opts)
+        },
+    )
+}
+
+// Runs a day giving up on stages that take longer than `timeout`. Those
+// keep running detached and borrowing the input, so it has to live until
+// the process exits.
+pub fn run_with_timeout<D, S>(
+    year: usize,
+    day: usize,
+    input: &'static str,
+    opts: &RunOptions,
+    stages: Stages<'static, D, S>,
+    timeout: Duration,
+) -> DayReport
+where
+    D: Day<'static> + Send + Sync + 'static,
+    D::Part1Output: Send + 'static,
+    D::Part2Output: Send + 'static,
+    S: Send + Sync + 'static,
+{
+    run_stages(
+        year,
+        day,
+        input,
+        opts,
+        stages,
+        |solution, stages, expected_1, expected_2| {
+            run_parts_with_timeout(solution, stages, expected_1, expected_2, opts, timeout)
+        },
+    )
+}
+
+#[derive(Clone, Copy)]
 pub enum InputType {
     Examples,
     Puzzles,
 }
 
 impl InputType {
-    fn dir(&self) -> &str {
+    pub fn dir(&self) -> &str {
         match self {
             Self::Examples => "examples",
             Self::Puzzles => "puzzles",
@@ -60,16 +744,290 @@ impl InputType {
     }
 }
 
-pub trait Year {
-    fn year(&self) -> usize;
-    fn solve(&self, day: usize, input_type: InputType);
-    fn get_input(&self, day: usize, input_type: InputType) -> String {
-        let path = format!(
-            "inputs/{}/{}/day{:02}.txt",
-            self.year(),
+pub fn input_path(year: usize, day: usize, input_type: InputType, example: Option<&str>) -> String {
+    match example {
+        Some(example) => format!(
+            "inputs/{}/{}/day{:02}-{}.txt",
+            year,
             input_type.dir(),
             day,
-        );
-        fs::read_to_string(path).expect("Input file could not be read")
+            example
+        ),
+        None => format!("inputs/{}/{}/day{:02}.txt", year, input_type.dir(), day),
+    }
+}
+
+// Finds the example inputs of a day, where `None` is the unlettered `dayNN.txt`.
+// Falls back to just that one when none exist, so that it is reported as missing.
+pub fn example_names(year: usize, day: usize) -> Vec<Option<String>> {
+    let dir = format!("inputs/{}/{}", year, InputType::Examples.dir());
+    let prefix = format!("day{:02}", day);
+    let mut names = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            match name.strip_prefix('-') {
+                Some(example) => Some(Some(example.to_string())),
+                None if name.is_empty() => Some(None),
+                None => None,
+            }
+        })
+        .collect::<Vec<Option<String>>>();
+    names.sort();
+    if names.is_empty() {
+        names.push(None);
+    }
+    names
+}
+
+pub struct DayEntry {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    pub solve: fn(&str, &RunOptions) -> DayReport,
+}
+
+impl DayEntry {
+    pub fn input_path(&self, opts: &RunOptions) -> String {
+        opts.input.clone().unwrap_or_else(|| {
+            input_path(
+                self.year,
+                self.day,
+                opts.input_type,
+                opts.example.as_deref(),
+            )
+        })
+    }
+    pub fn get_input(&self, opts: &RunOptions) -> io::Result<String> {
+        match opts.input.as_deref() {
+            Some("-") => io::read_to_string(io::stdin()),
+            _ => fs::read_to_string(self.input_path(opts)),
+        }
+    }
+    pub fn run(&self, opts: &RunOptions) -> DayReport {
+        match self.get_input(opts) {
+            Ok(input) => {
+                let (input, changes) = normalize(&input, opts.trim_lines);
+                if opts.verbose && !changes.is_empty() {
+                    let path = match self.input_path(opts) {
+                        path if path == "-" => "stdin".to_string(),
+                        path => path,
+                    };
+                    eprintln!("Normalized {}: {}", path, changes.join(", "));
+                }
+                (self.solve)(&input, opts)
+            }
+            Err(err) => DayReport::failed(
+                self.year,
+                self.day,
+                self.title,
+                opts,
+                Timing::none(),
+                DayError::Input(self.input_path(opts), err),
+            ),
+        }
     }
 }
+
+// Registers every listed day of a year: adds it to the year's `DAYS`
+// table and generates its tests. Attributes on an entry (such as
+// `#[ignore = "slow"]`) are applied to its tests. The day modules are
+// declared above the list with plain `mod dayNN;` lines, which rustfmt
+// follows while it skips modules declared inside macros.
+macro_rules! days {
+    ($year:literal; $($(#[$attr:meta])* $day:literal => $module:ident::$solution:ident $(($kind:ident))?),* $(,)?) => {
+        pub const DAYS: &[crate::solution::DayEntry] = &[
+            $(
+                crate::solution::DayEntry {
+                    year: $year,
+                    day: $day,
+                    title: <$module::$solution as crate::solution::Day>::TITLE,
+                    params: <$module::$solution as crate::solution::Day>::PARAMS,
+                    solve: |input, opts| match opts.timeout {
+                        Some(timeout) => crate::solution::run_with_timeout(
+                            $year,
+                            $day,
+                            Box::leak(input.into()),
+                            opts,
+                            crate::solution::day_stages!($($kind)?; $module::$solution),
+                            timeout,
+                        ),
+                        None => crate::solution::run(
+                            $year,
+                            $day,
+                            input,
+                            opts,
+                            crate::solution::day_stages!($($kind)?; $module::$solution),
+                        ),
+                    },
+                },
+            )*
+        ];
+
+        #[cfg(test)]
+        mod tests {
+            crate::solution::day_tests! {
+                $year;
+                $($(#[$attr])* $day => $module::$solution),*
+            }
+        }
+    };
+}
+
+pub(crate) use days;
+
+// Picks the stages of a registered day from its `(kind)`.
+macro_rules! day_stages {
+    (; $solution:ty) => {
+        crate::solution::stages::<$solution>()
+    };
+    (shared; $solution:ty) => {
+        crate::solution::shared_stages::<$solution>()
+    };
+}
+
+pub(crate) use day_stages;
+
+// Reads the inputs to test, which are all examples for `InputType::Examples`.
+#[cfg(test)]
+pub fn test_inputs(
+    year: usize,
+    day: usize,
+    input_type: InputType,
+) -> Vec<(Option<String>, String)> {
+    let examples = match input_type {
+        InputType::Examples => example_names(year, day),
+        InputType::Puzzles => vec![None],
+    };
+    examples
+        .into_iter()
+        .filter_map(|example| {
+            let path = input_path(year, day, input_type, example.as_deref());
+            match fs::read_to_string(&path) {
+                Ok(input) => Some((example, normalize(&input, false).0)),
+                Err(_) => {
+                    eprintln!("Skipping, {} could not be read", path);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn test_answer<T: Display>(answer: impl FnOnce() -> T, expected: Expected<String>) {
+    match expected {
+        Expected::Answer(expected) => assert_eq!(answer().to_string(), expected),
+        Expected::Unknown => eprintln!("Skipping, no known solution"),
+        Expected::NotApplicable => eprintln!("Skipping, example does not apply"),
+    }
+}
+
+#[cfg(test)]
+pub fn test_part_1<'i, D: Day<'i>>(
+    year: usize,
+    day: usize,
+    input: &'i str,
+    input_type: InputType,
+    example: Option<&str>,
+) {
+    let mut solution = D::try_parse(input).unwrap_or_else(|err| panic!("{}", err));
+    solution
+        .set_params(&Params::resolve(D::PARAMS, input_type, &[]))
+        .unwrap();
+    let answers = Answers::load(year).unwrap();
+    let expected = expected_answers(&solution, &answers, day, input_type, example).0;
+    test_answer(|| solution.solve_part_1(), expected);
+}
+
+#[cfg(test)]
+pub fn test_part_2<'i, D: Day<'i>>(
+    year: usize,
+    day: usize,
+    input: &'i str,
+    input_type: InputType,
+    example: Option<&str>,
+) {
+    let mut solution = D::try_parse(input).unwrap_or_else(|err| panic!("{}", err));
+    solution
+        .set_params(&Params::resolve(D::PARAMS, input_type, &[]))
+        .unwrap();
+    let answers = Answers::load(year).unwrap();
+    let expected = expected_answers(&solution, &answers, day, input_type, example).1;
+    test_answer(|| solution.solve_part_2(), expected);
+}
+
+// Generates a test per part and input type for every listed day,
+// checking the answers against the answers file and `Day::solution`.
+#[cfg(test)]
+macro_rules! day_tests {
+    ($year:literal; $($(#[$attr:meta])* $day:literal => $module:ident::$solution:ident $(($kind:ident))?),* $(,)?) => {
+        $(
+            mod $module {
+                use crate::solution::{test_inputs, test_part_1, test_part_2, InputType};
+
+                #[test]
+                $(#[$attr])*
+                fn part_1_examples() {
+                    for (example, input) in test_inputs($year, $day, InputType::Examples) {
+                        test_part_1::<super::super::$module::$solution>(
+                            $year,
+                            $day,
+                            &input,
+                            InputType::Examples,
+                            example.as_deref(),
+                        );
+                    }
+                }
+
+                #[test]
+                $(#[$attr])*
+                fn part_1_puzzles() {
+                    for (example, input) in test_inputs($year, $day, InputType::Puzzles) {
+                        test_part_1::<super::super::$module::$solution>(
+                            $year,
+                            $day,
+                            &input,
+                            InputType::Puzzles,
+                            example.as_deref(),
+                        );
+                    }
+                }
+
+                #[test]
+                $(#[$attr])*
+                fn part_2_examples() {
+                    for (example, input) in test_inputs($year, $day, InputType::Examples) {
+                        test_part_2::<super::super::$module::$solution>(
+                            $year,
+                            $day,
+                            &input,
+                            InputType::Examples,
+                            example.as_deref(),
+                        );
+                    }
+                }
+
+                #[test]
+                $(#[$attr])*
+                fn part_2_puzzles() {
+                    for (example, input) in test_inputs($year, $day, InputType::Puzzles) {
+                        test_part_2::<super::super::$module::$solution>(
+                            $year,
+                            $day,
+                            &input,
+                            InputType::Puzzles,
+                            example.as_deref(),
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+pub(crate) use day_tests;
@@ -0,0 +1,75 @@
+use crate::{
+    answers::answers_path,
+    report, runner,
+    solution::{example_names, DayEntry, DayReport, RunOptions},
+};
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+fn watched_paths(entry: &DayEntry, opts: &RunOptions, all_examples: bool) -> Vec<String> {
+    let mut paths = if all_examples {
+        example_names(entry.year, entry.day)
+            .into_iter()
+            .map(|example| {
+                entry.input_path(&RunOptions {
+                    example,
+                    ..opts.clone()
+                })
+            })
+            .collect()
+    } else {
+        vec![entry.input_path(opts)]
+    };
+    paths.push(answers_path(entry.year));
+    paths
+}
+
+// Files that do not exist (yet) have no modification time.
+fn modified(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+// Polls the inputs and the answers file of the day, and re-runs it when
+// any of them changes, printing how the answers differ from the last run.
+// Keeps watching until the process is interrupted.
+pub fn watch(
+    entry: &DayEntry,
+    opts: &RunOptions,
+    all_examples: bool,
+    mut reports: Vec<DayReport>,
+) -> ! {
+    let mut paths = watched_paths(entry, opts, all_examples);
+    let mut times = modified(&paths);
+    eprintln!("Watching {} for changes", paths.join(", "));
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let new_paths = watched_paths(entry, opts, all_examples);
+        let new_times = modified(&new_paths);
+        if new_paths == paths && new_times == times {
+            continue;
+        }
+        let changed = new_paths
+            .iter()
+            .zip(&new_times)
+            .filter(|&(path, time)| {
+                !paths
+                    .iter()
+                    .zip(&times)
+                    .any(|(old_path, old_time)| old_path == path && old_time == time)
+            })
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<&str>>();
+        println!();
+        println!("Changed {}, re-running", changed.join(", "));
+        let new_reports = runner::run_entry(entry, opts, all_examples);
+        report::print_changes(&reports, &new_reports);
+        (paths, times, reports) = (new_paths, new_times, new_reports);
+    }
+}
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/1
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseResult},
+    solution::{Day, InputType},
+};
 
 struct Top3 {
     list: [usize; 3],
@@ -44,20 +47,14 @@ impl<'i> Day<'i> for Day01 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Calorie Counting"
-    }
+    const TITLE: &'static str = "Calorie Counting";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input
             .split("\n\n")
-            .map(|elf| {
-                elf.split('\n')
-                    .map(|cal| cal.parse::<usize>().unwrap())
-                    .sum()
-            })
-            .collect::<Vec<usize>>();
-        Self { data }
+            .map(|elf| elf.split('\n').map(|cal| number::<usize>(input, cal)).sum())
+            .collect::<ParseResult<Vec<usize>>>()?;
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/2
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 
 const OP_ROCK: u8 = b'A';
 const OP_PAPER: u8 = b'B';
@@ -30,19 +33,21 @@ impl<'i> Day<'i> for Day02 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Rock Paper Scissors"
-    }
+    const TITLE: &'static str = "Rock Paper Scissors";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input
             .split('\n')
             .map(|line| match line.as_bytes() {
-                [op, b' ', me] => (*op, *me),
-                _ => unreachable!(),
+                [op @ b'A'..=b'C', b' ', me @ b'X'..=b'Z'] => Ok((*op, *me)),
+                _ => Err(ParseError::new(
+                    input,
+                    line,
+                    "expected a round like \"A Y\"",
+                )),
             })
-            .collect::<Vec<(u8, u8)>>();
-        Self { data }
+            .collect::<ParseResult<Vec<(u8, u8)>>>()?;
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
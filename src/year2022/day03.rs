@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/3
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use std::collections::HashSet;
 
 pub struct Day03 {
@@ -11,11 +14,9 @@ impl<'i> Day<'i> for Day03 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Rucksack Reorganization"
-    }
+    const TITLE: &'static str = "Rucksack Reorganization";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input
             .split('\n')
             .map(|line| {
@@ -29,7 +30,7 @@ impl<'i> Day<'i> for Day03 {
                     .collect::<Vec<usize>>()
             })
             .collect::<Vec<Vec<usize>>>();
-        Self { data }
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
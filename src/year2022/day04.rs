@@ -1,7 +1,9 @@
 // https://adventofcode.com/2022/day/4
 
-use crate::solution::{Day, InputType};
-use itertools::Itertools;
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 
 type Assignment = ((usize, usize), (usize, usize));
 
@@ -13,27 +15,21 @@ impl<'i> Day<'i> for Day04 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Camp Cleanup"
-    }
+    const TITLE: &'static str = "Camp Cleanup";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let range = |text: &str| -> ParseResult<(usize, usize)> {
+            let (start, end) = split_once(input, text, "-")?;
+            Ok((number(input, start)?, number(input, end)?))
+        };
         let data = input
             .split('\n')
             .map(|line| {
-                line.split(',')
-                    .map(|range| {
-                        range
-                            .splitn(2, '-')
-                            .map(|num| num.parse::<usize>().unwrap())
-                            .collect_tuple()
-                            .unwrap()
-                    })
-                    .collect_tuple()
-                    .unwrap()
+                let (first, second) = split_once(input, line, ",")?;
+                Ok((range(first)?, range(second)?))
             })
-            .collect::<Vec<Assignment>>();
-        Self { data }
+            .collect::<ParseResult<Vec<Assignment>>>()?;
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,7 +1,9 @@
 // https://adventofcode.com/2022/day/5
 
-use crate::solution::{InputType, Day};
-use itertools::Itertools;
+use crate::{
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 
 type Stacks = Vec<Vec<char>>;
 type Moves = Vec<(usize, usize, usize)>;
@@ -15,12 +17,10 @@ impl<'i> Day<'i> for Day05 {
     type Part1Output = String;
     type Part2Output = String;
 
-    fn title(&self) -> &str {
-        "Supply Stacks"
-    }
+    const TITLE: &'static str = "Supply Stacks";
 
-    fn parse(input: &'i str) -> Self {
-        let (stack_lines, move_lines) = input.split("\n\n").collect_tuple().unwrap();
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let (stack_lines, move_lines) = split_once(input, input, "\n\n")?;
         let mut stack_iter = stack_lines.rsplit('\n');
         let mut stacks = Vec::new();
         let stack_amt = (stack_iter.next().unwrap().len() + 1) / 4;
@@ -38,17 +38,23 @@ impl<'i> Day<'i> for Day05 {
         }
         let moves = move_lines
             .split('\n')
-            .map(|mov_line| {
-                let mov = mov_line.split(' ').collect::<Vec<&str>>();
-                (
-                    mov[1].parse::<usize>().unwrap(),
-                    mov[3].parse::<usize>().unwrap(),
-                    mov[5].parse::<usize>().unwrap(),
-                )
-            })
-            .collect::<Moves>();
+            .map(
+                |mov_line| match mov_line.split(' ').collect::<Vec<&str>>()[..] {
+                    ["move", amount, "from", from, "to", to] => Ok((
+                        number(input, amount)?,
+                        number(input, from)?,
+                        number(input, to)?,
+                    )),
+                    _ => Err(ParseError::new(
+                        input,
+                        mov_line,
+                        "expected a move like \"move 1 from 2 to 3\"",
+                    )),
+                },
+            )
+            .collect::<ParseResult<Moves>>()?;
 
-        Self { stacks, moves }
+        Ok(Self { stacks, moves })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
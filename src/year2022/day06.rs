@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/6
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use std::collections::HashSet;
 
@@ -12,13 +15,11 @@ impl<'i> Day<'i> for Day06 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Tuning Trouble"
-    }
+    const TITLE: &'static str = "Tuning Trouble";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input.chars().collect();
-        Self { data }
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/7
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseResult},
+    solution::{Day, InputType},
+};
 
 struct FileSystem {
     files: Vec<Node>,
@@ -63,11 +66,9 @@ impl<'i> Day<'i> for Day07 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "No Space Left On Device"
-    }
+    const TITLE: &'static str = "No Space Left On Device";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut fs = FileSystem::new();
         let mut cwd = 0;
         for line in input.split('\n') {
@@ -79,11 +80,11 @@ impl<'i> Day<'i> for Day07 {
                 ["$", "ls"] => (),
                 // Nodes
                 ["dir", _] => (),
-                [size, _] => fs.add_file(cwd, size.parse::<usize>().unwrap()),
+                [size, _] => fs.add_file(cwd, number(input, size)?),
                 _ => (),
             }
         }
-        Self { fs }
+        Ok(Self { fs })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/8
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use std::collections::HashSet;
 
@@ -13,11 +16,9 @@ impl<'i> Day<'i> for Day08 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Treetop Tree House"
-    }
+    const TITLE: &'static str = "Treetop Tree House";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         // Assume the input is square, so the amount
         // of lines is equal to the dimensions.
         let mut dim = 1;
@@ -32,7 +33,7 @@ impl<'i> Day<'i> for Day08 {
                 _ => Some((x - b'0') as usize),
             })
             .collect();
-        Self { data, dim }
+        Ok(Self { data, dim })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/9
 
-use crate::solution::{Day, InputType};
+use crate::{
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, Expected, InputType},
+};
 use std::collections::HashSet;
 
 pub struct Day09 {
@@ -11,21 +14,21 @@ impl<'i> Day<'i> for Day09 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Rope Bridge"
-    }
+    const TITLE: &'static str = "Rope Bridge";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let moves = input
             .split('\n')
             .map(|line| {
-                let mut iter = line.chars();
-                let dir = iter.next().unwrap();
-                let num = iter.skip(1).collect::<String>().parse::<usize>().unwrap();
-                (dir, num)
+                let (dir, num) = split_once(input, line, " ")?;
+                let dir = match dir.as_bytes() {
+                    [dir @ (b'U' | b'D' | b'L' | b'R')] => *dir as char,
+                    _ => return Err(ParseError::new(input, dir, "expected U, D, L or R")),
+                };
+                Ok((dir, number(input, num)?))
             })
-            .collect::<Vec<(char, usize)>>();
-        Self { moves }
+            .collect::<ParseResult<Vec<(char, usize)>>>()?;
+        Ok(Self { moves })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -45,6 +48,16 @@ impl<'i> Day<'i> for Day09 {
             InputType::Puzzles => (Some(5960), Some(2327)),
         }
     }
+
+    fn example_solution(
+        &self,
+        name: &str,
+    ) -> (Expected<Self::Part1Output>, Expected<Self::Part2Output>) {
+        match name {
+            "a" => (Expected::Answer(13), Expected::Answer(1)),
+            _ => (Expected::Unknown, Expected::Unknown),
+        }
+    }
 }
 
 impl Day09 {
@@ -1,6 +1,10 @@
 // https://adventofcode.com/2022/day/10
 
-use crate::solution::{InputType, Day};
+use crate::{
+    grids::Grid,
+    parsing::{number, ParseError, ParseResult},
+    solution::Day,
+};
 
 enum Instr {
     Noop,
@@ -15,7 +19,7 @@ struct Cpu {
 struct Crt {
     cpu: Cpu,
     dot: isize,
-    display: String,
+    pixels: Vec<bool>,
 }
 
 impl Cpu {
@@ -40,7 +44,7 @@ impl Crt {
         Self {
             cpu: Cpu::new(),
             dot: 0,
-            display: String::new(),
+            pixels: Vec::new(),
         }
     }
 
@@ -49,10 +53,9 @@ impl Crt {
     }
 
     fn step(&mut self, instr: &Instr) {
-        self.display.push(if self.get_pixel() { '#' } else { '.' });
+        self.pixels.push(self.get_pixel());
         self.dot += 1;
         if self.dot >= 40 {
-            self.display.push('\n');
             self.dot = 0;
         }
         self.cpu.step(instr);
@@ -67,21 +70,25 @@ impl<'i> Day<'i> for Day10 {
     type Part1Output = isize;
     type Part2Output = String;
 
-    fn title(&self) -> &str {
-        "Cathode-Ray Tube"
-    }
+    const TITLE: &'static str = "Cathode-Ray Tube";
 
-    fn parse(input: &'i str) -> Self {
-        let instrs = input
-            .split('\n')
-            .flat_map(|line| match line.split(' ').collect::<Vec<&str>>()[..] {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let mut instrs = Vec::new();
+        for line in input.split('\n') {
+            match line.split(' ').collect::<Vec<&str>>()[..] {
                 // Add an extra NOOP in front of every ADDX to make it take 2 cycle.
-                ["noop"] => vec![Instr::Noop],
-                ["addx", x] => vec![Instr::Noop, Instr::Addx(x.parse::<isize>().unwrap())],
-                _ => unreachable!(),
-            })
-            .collect::<Vec<Instr>>();
-        Self { instrs }
+                ["noop"] => instrs.push(Instr::Noop),
+                ["addx", x] => instrs.extend([Instr::Noop, Instr::Addx(number(input, x)?)]),
+                _ => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        "expected \"noop\" or \"addx N\"",
+                    ))
+                }
+            }
+        }
+        Ok(Self { instrs })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -105,16 +112,8 @@ impl<'i> Day<'i> for Day10 {
         for instr in &self.instrs {
             crt.step(instr);
         }
-        crt.display
-    }
-
-    fn solution(
-        &self,
-        input_type: crate::solution::InputType,
-    ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
-        match input_type {
-            InputType::Examples => (Some(13140), Some("##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....\n".into())),
-            InputType::Puzzles => (Some(14540), Some("####.#..#.####.####.####.#..#..##..####.\n#....#..#....#.#.......#.#..#.#..#....#.\n###..####...#..###....#..####.#......#..\n#....#..#..#...#.....#...#..#.#.....#...\n#....#..#.#....#....#....#..#.#..#.#....\n####.#..#.####.#....####.#..#..##..####.\n".into())),
-        }
+        // Every row of the display ends in a newline, the last one included.
+        let display = Grid::from_vec(crt.pixels, 40).render(|&lit| if lit { '#' } else { '.' });
+        display + "\n"
     }
 }
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/11
 
-use crate::solution::{Day, InputType};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use std::collections::VecDeque;
 
@@ -49,7 +52,7 @@ impl Monkey {
     }
 
     fn throw_to(&self, item: usize) -> usize {
-        if item % self.test == 0 {
+        if item.is_multiple_of(self.test) {
             return self.ttrue;
         }
         self.tfalse
@@ -64,11 +67,9 @@ impl<'i> Day<'i> for Day11 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Monkey in the Middle"
-    }
+    const TITLE: &'static str = "Monkey in the Middle";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut monkeys = Vec::new();
         for text in input.split("\n\n") {
             let monkey = text.split('\n').collect::<Vec<&str>>();
@@ -90,7 +91,7 @@ impl<'i> Day<'i> for Day11 {
             let tfalse = monkey[5].split_at(30).1.parse::<usize>().unwrap();
             monkeys.push(Monkey::new(items, operation, test, ttrue, tfalse));
         }
-        Self { monkeys }
+        Ok(Self { monkeys })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
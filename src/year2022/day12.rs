@@ -1,13 +1,14 @@
 // https://adventofcode.com/2022/day/12
 
 use crate::{
-    grids::UPoint,
+    grids::{Grid, UPoint},
+    parsing::ParseResult,
     solution::{Day, InputType},
 };
 use std::collections::{HashMap, VecDeque};
 
 type StartState = VecDeque<UPoint>;
-type Map = Vec<Vec<usize>>;
+type Map = Grid<usize>;
 
 pub struct Day12 {
     map: Map,
@@ -20,45 +21,26 @@ impl<'i> Day<'i> for Day12 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Hill Climbing Algorithm"
-    }
+    const TITLE: &'static str = "Hill Climbing Algorithm";
 
-    fn parse(input: &'i str) -> Self {
-        let mut start1 = VecDeque::new();
-        let mut start2 = VecDeque::new();
-        let mut end = UPoint::new(0, 0);
-        let map = input
-            .split('\n')
-            .enumerate()
-            .map(|(y, line)| {
-                line.bytes()
-                    .enumerate()
-                    .map(|(x, char)| match char {
-                        b'S' => {
-                            start1.push_back(UPoint::new(x, y));
-                            start2.push_back(UPoint::new(x, y));
-                            0
-                        }
-                        b'E' => {
-                            end = UPoint::new(x, y);
-                            25
-                        }
-                        b'a' => {
-                            start2.push_back(UPoint::new(x, y));
-                            0
-                        }
-                        byte => (byte - b'a') as usize,
-                    })
-                    .collect::<Vec<usize>>()
-            })
-            .collect::<Vec<Vec<usize>>>();
-        Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let (map, [start, end]) = Grid::parse_marked(input, ['S', 'E'], |c| match c {
+            'S' => Some(0),
+            'E' => Some(25),
+            'a'..='z' => Some(c as usize - 'a' as usize),
+            _ => None,
+        })?;
+        let start1 = VecDeque::from([start]);
+        let start2 = (0..map.height())
+            .flat_map(|y| (0..map.width()).map(move |x| UPoint::new(x, y)))
+            .filter(|pos| *map.get(pos) == 0)
+            .collect::<StartState>();
+        Ok(Self {
             map,
             start1,
             start2,
             end,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -75,7 +57,7 @@ impl<'i> Day<'i> for Day12 {
     ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
         match input_type {
             InputType::Examples => (Some(31), Some(29)),
-            InputType::Puzzles => (Some(408), Some(339)),
+            InputType::Puzzles => (Some(408), Some(399)),
         }
     }
 }
@@ -83,8 +65,8 @@ impl<'i> Day<'i> for Day12 {
 impl Day12 {
     fn solve(&self, queue: &mut StartState) -> usize {
         // Initialise BFS
-        let height = self.map.len();
-        let width = self.map[0].len();
+        let height = self.map.height();
+        let width = self.map.width();
         let mut visited = HashMap::<UPoint, UPoint>::new();
         for start in queue.iter() {
             visited.insert(*start, *start);
@@ -95,10 +77,10 @@ impl Day12 {
             if pos == self.end {
                 break;
             }
-            let size = self.map[pos.y][pos.x];
+            let size = *self.map.get(&pos);
 
             for new_pos in pos.neighbors_4_in(width, height) {
-                if self.map[new_pos.y][new_pos.x] <= size + 1 && !visited.contains_key(&new_pos) {
+                if *self.map.get(&new_pos) <= size + 1 && !visited.contains_key(&new_pos) {
                     queue.push_back(new_pos);
                     visited.insert(new_pos, pos);
                 }
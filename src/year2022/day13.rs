@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/13
 
-use crate::solution::{Day, InputType};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use itertools::{EitherOrBoth, Itertools};
 use std::{
     cmp::{Ordering, PartialOrd},
@@ -55,11 +58,9 @@ impl<'i> Day<'i> for Day13 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Distress Signal"
-    }
+    const TITLE: &'static str = "Distress Signal";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let elements = input
             .split('\n')
             .filter_map(|elem| match elem {
@@ -67,7 +68,7 @@ impl<'i> Day<'i> for Day13 {
                 _ => Some(Self::parse_list(&mut elem[1..].chars())),
             })
             .collect::<Vec<Element>>();
-        Self { elements }
+        Ok(Self { elements })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
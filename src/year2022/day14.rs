@@ -1,64 +1,64 @@
 // https://adventofcode.com/2022/day/14
 
-use crate::solution::{InputType, Day};
+use crate::{
+    grids::{IPoint, SparseGrid},
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
-use std::collections::HashSet;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Cell {
+    Rock,
+    Sand,
+}
 
 pub struct Day14 {
-    points: HashSet<(usize, usize)>,
-    lowest: usize,
+    cave: SparseGrid<Cell>,
+    lowest: isize,
 }
 
 impl<'i> Day<'i> for Day14 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Regolith Reservoir"
-    }
+    const TITLE: &'static str = "Regolith Reservoir";
 
-    fn parse(input: &'i str) -> Self {
-        let mut points = HashSet::new();
-        let mut lowest = 0;
-        input.split('\n').for_each(|line| {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let mut cave = SparseGrid::new();
+        for line in input.split('\n') {
             line.split(" -> ")
                 .map(|pt| {
-                    pt.split(',')
-                        .map(|num| num.parse::<usize>().unwrap())
-                        .collect_tuple::<(usize, usize)>()
-                        .unwrap()
+                    let (x, y) = split_once(input, pt, ",")?;
+                    Ok(IPoint::new(number(input, x)?, number(input, y)?))
                 })
-                .tuple_windows::<((usize, usize), (usize, usize))>()
+                .collect::<ParseResult<Vec<IPoint>>>()?
+                .into_iter()
+                .tuple_windows::<(IPoint, IPoint)>()
                 .for_each(|(pt1, pt2)| {
-                    if pt1.1 > lowest {
-                        lowest = pt1.1;
-                    }
                     let mut pt = pt1;
-                    let xdir = ((pt2.0 as isize) - (pt1.0 as isize)).clamp(-1, 1);
-                    let ydir = ((pt2.1 as isize) - (pt1.1 as isize)).clamp(-1, 1);
+                    let step = IPoint::new((pt2.x - pt1.x).signum(), (pt2.y - pt1.y).signum());
                     while pt != pt2 {
-                        points.insert(pt);
-                        pt.0 = (pt.0 as isize + xdir) as usize;
-                        pt.1 = (pt.1 as isize + ydir) as usize;
+                        cave.insert(pt, Cell::Rock);
+                        pt = pt + step;
                     }
-                    points.insert(pt2);
-                })
-        });
-        Self { points, lowest }
+                    cave.insert(pt2, Cell::Rock);
+                });
+        }
+        let lowest = cave.bounds().map_or(0, |(_, max)| max.y);
+        Ok(Self { cave, lowest })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
-        let points = &mut self.points.clone();
-        let orig_size = points.len();
-        self.find_abyss((500, 0), points);
-        points.len() - orig_size
+        let cave = &mut self.cave.clone();
+        self.find_abyss(IPoint::new(500, 0), cave);
+        cave.count(|cell| *cell == Cell::Sand)
     }
 
     fn solve_part_2(&self) -> Self::Part2Output {
-        let points = &mut self.points.clone();
-        let orig_size = points.len();
-        self.fill_cave((500, 0), points);
-        points.len() - orig_size
+        let cave = &mut self.cave.clone();
+        self.fill_cave(IPoint::new(500, 0), cave);
+        cave.count(|cell| *cell == Cell::Sand)
     }
 
     fn solution(
@@ -73,31 +73,31 @@ impl<'i> Day<'i> for Day14 {
 }
 
 impl Day14 {
-    fn find_abyss(&self, sand: (usize, usize), points: &mut HashSet<(usize, usize)>) -> bool {
+    fn find_abyss(&self, sand: IPoint, cave: &mut SparseGrid<Cell>) -> bool {
         let abyss = self.lowest;
-        if sand.1 == abyss {
+        if sand.y == abyss {
             return true;
         }
         for xdiff in [0, -1, 1] {
-            let new = ((sand.0 as isize + xdiff) as usize, sand.1 + 1);
-            if !points.contains(&new) && self.find_abyss(new, points) {
+            let new = IPoint::new(sand.x + xdiff, sand.y + 1);
+            if !cave.contains(&new) && self.find_abyss(new, cave) {
                 return true;
             }
         }
-        points.insert(sand);
+        cave.insert(sand, Cell::Sand);
         false
     }
 
-    fn fill_cave(&self, sand: (usize, usize), points: &mut HashSet<(usize, usize)>) {
+    fn fill_cave(&self, sand: IPoint, cave: &mut SparseGrid<Cell>) {
         let floor = self.lowest + 1;
-        points.insert(sand);
-        if sand.1 == floor {
+        cave.insert(sand, Cell::Sand);
+        if sand.y == floor {
             return;
         }
         for xdiff in [0, -1, 1] {
-            let new = ((sand.0 as isize + xdiff) as usize, sand.1 + 1);
-            if !points.contains(&new) {
-                self.fill_cave(new, points);
+            let new = IPoint::new(sand.x + xdiff, sand.y + 1);
+            if !cave.contains(&new) {
+                self.fill_cave(new, cave);
             }
         }
     }
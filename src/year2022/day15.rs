@@ -2,7 +2,8 @@
 
 use crate::{
     grids::IPoint,
-    solution::{Day, InputType},
+    parsing::ParseResult,
+    solution::{Day, InputType, Param, Params},
 };
 use itertools::Itertools;
 
@@ -34,17 +35,17 @@ fn overlapping(ranges: Vec<Range>) -> Option<isize> {
 
 pub struct Day15 {
     data: Vec<(IPoint, IPoint)>,
+    row: isize,
+    range: isize,
 }
 
 impl<'i> Day<'i> for Day15 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Beacon Exclusion Zone"
-    }
+    const TITLE: &'static str = "Beacon Exclusion Zone";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input
             .split('\n')
             .map(|line| {
@@ -61,12 +62,34 @@ impl<'i> Day<'i> for Day15 {
                 )
             })
             .collect::<Vec<(IPoint, IPoint)>>();
-        Self { data }
+        Ok(Self {
+            data,
+            row: 0,
+            range: 0,
+        })
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "row",
+            examples: 10,
+            puzzles: 2_000_000,
+        },
+        Param {
+            name: "range",
+            examples: 20,
+            puzzles: 4_000_000,
+        },
+    ];
+
+    fn set_params(&mut self, params: &Params) -> Result<(), String> {
+        self.row = params.get("row")?;
+        self.range = params.get("range")?;
+        Ok(())
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
-        // Hardcode Y-coordinate if the input is the example.
-        let y_coord = if self.data.len() == 14 { 10 } else { 2_000_000 };
+        let y_coord = self.row;
 
         let mut min = 0;
         let mut max = 0;
@@ -85,10 +108,7 @@ impl<'i> Day<'i> for Day15 {
     }
 
     fn solve_part_2(&self) -> Self::Part2Output {
-        // Hardcoding example input again
-        let y_range = if self.data.len() == 14 { 20 } else { 4_000_000 };
-
-        for y_coord in 0..y_range {
+        for y_coord in 0..self.range {
             let mut ranges = vec![];
             self.data.iter().for_each(|(sensor, beacon)| {
                 if let Some(range) = make_range(sensor, beacon, y_coord) {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/16
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use regex::Regex;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -94,11 +97,9 @@ impl<'i> Day<'i> for Day16 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Proboscidea Volcanium"
-    }
+    const TITLE: &'static str = "Proboscidea Volcanium";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut cave = Cave::new();
         let re = Regex::new(
             r"Valve (.*?) has flow rate=(.*?); tunnels? leads? to valves? (.+(?:, |$))+?",
@@ -108,7 +109,7 @@ impl<'i> Day<'i> for Day16 {
             let cap = re.captures(line).unwrap();
             cave.add_valve(&cap[1], &cap[2], &cap[3]);
         });
-        Self { cave }
+        Ok(Self { cave })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -261,7 +262,7 @@ impl<'i> Day<'i> for Day16 {
     ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
         match input_type {
             InputType::Examples => (Some(1651), Some(1706)), // According to the website, the 2nd solution is 1707, but I get 1706. Actual puzzle solution is correct though.
-            InputType::Puzzles => (Some(5564017), Some(11558423398893)),
+            InputType::Puzzles => (Some(1741), Some(2316)),
         }
     }
 }
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/17
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
@@ -127,11 +130,9 @@ impl<'i> Day<'i> for Day17 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Pyroclastic Flow"
-    }
+    const TITLE: &'static str = "Pyroclastic Flow";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let jets = input
             .bytes()
             .map(|b| match b {
@@ -140,7 +141,7 @@ impl<'i> Day<'i> for Day17 {
                 _ => unreachable!(),
             })
             .collect::<Vec<Jet>>();
-        Self { jets }
+        Ok(Self { jets })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
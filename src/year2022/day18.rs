@@ -1,7 +1,9 @@
 // https://adventofcode.com/2022/day/18
 
-use crate::solution::{InputType, Day};
-use itertools::Itertools;
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Day18 {
@@ -12,18 +14,18 @@ impl<'i> Day<'i> for Day18 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Boiling Boulders"
-    }
+    const TITLE: &'static str = "Boiling Boulders";
 
-    fn parse(input: &'i str) -> Self {
-        let cubes = HashSet::from_iter(input.split('\n').map(|line| {
-            line.split(',')
-                .map(|num| num.parse::<isize>().unwrap())
-                .collect_tuple::<(isize, isize, isize)>()
-                .unwrap()
-        }));
-        Self { cubes }
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let cubes = input
+            .split('\n')
+            .map(|line| {
+                let (x, rest) = split_once(input, line, ",")?;
+                let (y, z) = split_once(input, rest, ",")?;
+                Ok((number(input, x)?, number(input, y)?, number(input, z)?))
+            })
+            .collect::<ParseResult<HashSet<(isize, isize, isize)>>>()?;
+        Ok(Self { cubes })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/19
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 use regex::Regex;
 use std::ops::{Add, Mul, Sub};
 
@@ -97,39 +100,39 @@ impl<'i> Day<'i> for Day19 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Not Enough Minerals"
-    }
+    const TITLE: &'static str = "Not Enough Minerals";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let re = Regex::new(r"Blueprint \d+: Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
         let blueprints = input
             .split('\n')
             .map(|line| {
-                let cap = re.captures(line).unwrap();
-                Blueprint {
+                let cap = re
+                    .captures(line)
+                    .ok_or_else(|| ParseError::new(input, line, "expected a blueprint"))?;
+                Ok(Blueprint {
                     ore_cost: Materials {
-                        ore: cap[1].parse::<usize>().unwrap(),
+                        ore: number(input, &cap[1])?,
                         ..Default::default()
                     },
                     clay_cost: Materials {
-                        ore: cap[2].parse::<usize>().unwrap(),
+                        ore: number(input, &cap[2])?,
                         ..Default::default()
                     },
                     obsidian_cost: Materials {
-                        ore: cap[3].parse::<usize>().unwrap(),
-                        clay: cap[4].parse::<usize>().unwrap(),
+                        ore: number(input, &cap[3])?,
+                        clay: number(input, &cap[4])?,
                         ..Default::default()
                     },
                     geode_cost: Materials {
-                        ore: cap[5].parse::<usize>().unwrap(),
-                        obsidian: cap[6].parse::<usize>().unwrap(),
+                        ore: number(input, &cap[5])?,
+                        obsidian: number(input, &cap[6])?,
                         ..Default::default()
                     },
-                }
+                })
             })
-            .collect::<Vec<Blueprint>>();
-        Self { blueprints }
+            .collect::<ParseResult<Vec<Blueprint>>>()?;
+        Ok(Self { blueprints })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
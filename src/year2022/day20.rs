@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/20
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseResult},
+    solution::{Day, InputType},
+};
 
 pub struct Day20 {
     data: Vec<isize>,
@@ -10,16 +13,14 @@ impl<'i> Day<'i> for Day20 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Grove Positioning System"
-    }
+    const TITLE: &'static str = "Grove Positioning System";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let data = input
             .split('\n')
-            .map(|line| line.parse().unwrap())
-            .collect();
-        Self { data }
+            .map(|line| number(input, line))
+            .collect::<ParseResult<Vec<isize>>>()?;
+        Ok(Self { data })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/21
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 use std::collections::HashMap;
 
 type JobAssignments = HashMap<String, Job>;
@@ -98,28 +101,32 @@ impl<'i> Day<'i> for Day21 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Monkey Math"
-    }
+    const TITLE: &'static str = "Monkey Math";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let assignments = input
             .split('\n')
             .map(|line| {
-                let (monkey, job) = line.split_once(": ").unwrap();
+                let (monkey, job) = split_once(input, line, ": ")?;
                 let job = match job.split(' ').collect::<Vec<&str>>()[..] {
-                    [num] if monkey == "humn" => Job::Human(num.parse().unwrap()),
-                    [num] => Job::Number(num.parse().unwrap()),
+                    [num] if monkey == "humn" => Job::Human(number(input, num)?),
+                    [num] => Job::Number(number(input, num)?),
                     [lhs, "+", rhs] => Job::Operator(lhs.to_string(), Op::Add, rhs.to_string()),
                     [lhs, "-", rhs] => Job::Operator(lhs.to_string(), Op::Sub, rhs.to_string()),
                     [lhs, "*", rhs] => Job::Operator(lhs.to_string(), Op::Mul, rhs.to_string()),
                     [lhs, "/", rhs] => Job::Operator(lhs.to_string(), Op::Div, rhs.to_string()),
-                    _ => unreachable!(),
+                    _ => {
+                        return Err(ParseError::new(
+                            input,
+                            job,
+                            "expected a number or an operation",
+                        ))
+                    }
                 };
-                (monkey.to_string(), job)
+                Ok((monkey.to_string(), job))
             })
-            .collect::<JobAssignments>();
-        Self { assignments }
+            .collect::<ParseResult<JobAssignments>>()?;
+        Ok(Self { assignments })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
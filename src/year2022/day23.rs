@@ -1,33 +1,34 @@
 // https://adventofcode.com/2022/day/23
 
 use crate::{
-    grids::{Dir, IPoint},
+    grids::{Dir, IPoint, SparseGrid},
+    parsing::ParseResult,
     solution::{Day, InputType},
 };
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
+
+type Elves = SparseGrid<()>;
 
 pub struct Day23 {
-    elves: HashSet<IPoint>,
+    elves: Elves,
 }
 
 impl<'i> Day<'i> for Day23 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Unstable Diffusion"
-    }
+    const TITLE: &'static str = "Unstable Diffusion";
 
-    fn parse(input: &'i str) -> Self {
-        let mut elves = HashSet::new();
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let mut elves = SparseGrid::new();
         input.split('\n').enumerate().for_each(|(y, line)| {
             line.chars().enumerate().for_each(|(x, c)| {
                 if c == '#' {
-                    elves.insert(IPoint::new(x as isize, y as isize));
+                    elves.insert(IPoint::new(x as isize, y as isize), ());
                 }
             })
         });
-        Self { elves }
+        Ok(Self { elves })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -36,23 +37,7 @@ impl<'i> Day<'i> for Day23 {
         for _ in 0..10 {
             curr_elves = self.do_iteration(&curr_elves, &mut dirs);
         }
-        let (mut min_x, mut min_y, mut max_x, mut max_y) =
-            (isize::MAX, isize::MAX, isize::MIN, isize::MIN);
-        for elf in curr_elves.iter() {
-            if elf.x < min_x {
-                min_x = elf.x;
-            }
-            if elf.x > max_x {
-                max_x = elf.x;
-            }
-            if elf.y < min_y {
-                min_y = elf.y;
-            }
-            if elf.y > max_y {
-                max_y = elf.y;
-            }
-        }
-        (max_x - min_x + 1) * (max_y - min_y + 1) - curr_elves.len() as isize
+        (curr_elves.area() - curr_elves.len()) as isize
     }
 
     fn solve_part_2(&self) -> Self::Part2Output {
@@ -65,7 +50,7 @@ impl<'i> Day<'i> for Day23 {
         loop {
             iters += 1;
             new_elves = self.do_iteration(&curr_elves, &mut dirs);
-            if new_elves.iter().all(|p| curr_elves.contains(p)) {
+            if new_elves.iter().all(|(p, _)| curr_elves.contains(p)) {
                 break;
             }
             curr_elves = new_elves;
@@ -85,19 +70,11 @@ impl<'i> Day<'i> for Day23 {
 }
 
 impl Day23 {
-    fn do_iteration(
-        &self,
-        curr_elves: &HashSet<IPoint>,
-        dirs: &mut VecDeque<Dir>,
-    ) -> HashSet<IPoint> {
-        let mut next_elves = HashSet::new();
-        'elves: for curr_elf in curr_elves.iter() {
-            if !curr_elf
-                .neighbors_8()
-                .iter()
-                .any(|p| curr_elves.contains(p))
-            {
-                next_elves.insert(*curr_elf);
+    fn do_iteration(&self, curr_elves: &Elves, dirs: &mut VecDeque<Dir>) -> Elves {
+        let mut next_elves = SparseGrid::new();
+        'elves: for (curr_elf, _) in curr_elves.iter() {
+            if curr_elves.neighbors_8(curr_elf).next().is_none() {
+                next_elves.insert(*curr_elf, ());
                 continue;
             }
 
@@ -110,16 +87,16 @@ impl Day23 {
                     let next_elf = &curr_elf.dir(dir);
                     if next_elves.contains(next_elf) {
                         next_elves.remove(next_elf);
-                        next_elves.insert(*curr_elf);
-                        next_elves.insert(curr_elf.dir_steps(dir, 2));
+                        next_elves.insert(*curr_elf, ());
+                        next_elves.insert(curr_elf.dir_steps(dir, 2), ());
                     } else {
-                        next_elves.insert(*next_elf);
+                        next_elves.insert(*next_elf, ());
                     }
                     continue 'elves;
                 }
             }
 
-            next_elves.insert(*curr_elf);
+            next_elves.insert(*curr_elf, ());
         }
         let dir = dirs.pop_front().unwrap();
         dirs.push_back(dir);
@@ -1,7 +1,8 @@
 // https://adventofcode.com/2022/day/24
 
 use crate::{
-    grids::{Dir, IPoint},
+    grids::{Dir, Grid, IPoint, UPoint},
+    parsing::ParseResult,
     solution::{Day, InputType},
 };
 use std::collections::{HashSet, VecDeque};
@@ -14,13 +15,10 @@ struct Blizzard {
 }
 
 impl Blizzard {
-    fn step(&self, steps: isize, width: isize, height: isize) -> IPoint {
-        match self.dir {
-            Dir::Up => IPoint::new(self.start.x, (self.start.y - steps).rem_euclid(height)),
-            Dir::Right => IPoint::new((self.start.x + steps).rem_euclid(width), self.start.y),
-            Dir::Down => IPoint::new(self.start.x, (self.start.y + steps).rem_euclid(height)),
-            Dir::Left => IPoint::new((self.start.x - steps).rem_euclid(width), self.start.y),
-        }
+    // Blizzards leaving the valley come back in on the other side.
+    fn step(&self, steps: isize, valley: &Grid<Option<Dir>>) -> IPoint {
+        let loc = valley.wrap(&self.start.dir_steps(&self.dir, steps));
+        IPoint::new(loc.x as isize, loc.y as isize)
     }
 }
 
@@ -40,45 +38,33 @@ impl<'i> Day<'i> for Day24 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Blizzard Basin"
-    }
+    const TITLE: &'static str = "Blizzard Basin";
 
-    fn parse(input: &'i str) -> Self {
-        let mut width = 0;
-        // We skip the start walls, but not the end walls. Thus, start with -1.
-        let mut height = -1;
-        let blizzards = input
-            .split('\n')
-            .skip(1)
-            .enumerate()
-            .flat_map(|(y, l)| {
-                height += 1;
-                if y == 0 {
-                    width = l.len() as isize - 2;
-                }
-                l.chars()
-                    .skip(1)
-                    .enumerate()
-                    .filter_map(move |(x, c)| match c {
-                        '^' => Some(Blizzard {
-                            start: IPoint::new(x as isize, y as isize),
-                            dir: Dir::Up,
-                        }),
-                        '>' => Some(Blizzard {
-                            start: IPoint::new(x as isize, y as isize),
-                            dir: Dir::Right,
-                        }),
-                        'v' => Some(Blizzard {
-                            start: IPoint::new(x as isize, y as isize),
-                            dir: Dir::Down,
-                        }),
-                        '<' => Some(Blizzard {
-                            start: IPoint::new(x as isize, y as isize),
-                            dir: Dir::Left,
-                        }),
-                        _ => None,
-                    })
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        // Leave out the walls around the valley.
+        let lines = input.split('\n').collect::<Vec<&str>>();
+        let inner = &lines[1..lines.len() - 1];
+        let cells = inner
+            .iter()
+            .flat_map(|line| {
+                line[1..line.len() - 1].chars().map(|c| match c {
+                    '^' => Some(Dir::Up),
+                    '>' => Some(Dir::Right),
+                    'v' => Some(Dir::Down),
+                    '<' => Some(Dir::Left),
+                    _ => None,
+                })
+            })
+            .collect::<Vec<Option<Dir>>>();
+        let valley = Grid::from_vec(cells, inner[0].len() - 2);
+        let (width, height) = (valley.width() as isize, valley.height() as isize);
+        let blizzards = (0..valley.height())
+            .flat_map(|y| (0..valley.width()).map(move |x| UPoint::new(x, y)))
+            .filter_map(|loc| {
+                Some(Blizzard {
+                    start: IPoint::new(loc.x as isize, loc.y as isize),
+                    dir: (*valley.get(&loc))?,
+                })
             })
             .collect::<Vec<Blizzard>>();
         let mut states = Vec::new();
@@ -86,7 +72,7 @@ impl<'i> Day<'i> for Day24 {
         loop {
             let mut state = BlizzardState::new();
             for blizzard in &blizzards {
-                state.insert(blizzard.step(i, width, height));
+                state.insert(blizzard.step(i, &valley));
             }
             states.push(state);
             i += 1;
@@ -94,11 +80,11 @@ impl<'i> Day<'i> for Day24 {
                 break;
             }
         }
-        Self {
+        Ok(Self {
             states,
             width,
             height,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2022/day/25
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 
 struct Snafu(String);
 
@@ -77,16 +80,14 @@ impl<'i> Day<'i> for Day25 {
     type Part1Output = String;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Full of Hot Air"
-    }
+    const TITLE: &'static str = "Full of Hot Air";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let nums = input
             .split('\n')
             .map(|l| Snafu(l.to_string()))
             .collect::<Vec<Snafu>>();
-        Self { nums }
+        Ok(Self { nums })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/1
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, Expected, InputType},
+};
 
 pub struct Day01 {
     lines: Vec<String>,
@@ -10,16 +13,14 @@ impl<'i> Day<'i> for Day01 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Trebuchet?!"
-    }
+    const TITLE: &'static str = "Trebuchet?!";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let lines = input
             .split('\n')
             .map(|line| line.to_string())
             .collect::<Vec<String>>();
-        Self { lines }
+        Ok(Self { lines })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -84,4 +85,14 @@ impl<'i> Day<'i> for Day01 {
             InputType::Puzzles => (Some(55108), Some(56324)),
         }
     }
+
+    fn example_solution(
+        &self,
+        name: &str,
+    ) -> (Expected<Self::Part1Output>, Expected<Self::Part2Output>) {
+        match name {
+            "b" => (Expected::NotApplicable, Expected::Answer(281)),
+            _ => (Expected::Unknown, Expected::Unknown),
+        }
+    }
 }
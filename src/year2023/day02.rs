@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/2
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 
 struct Game {
     id: usize,
@@ -21,16 +24,14 @@ impl<'i> Day<'i> for Day02 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Cube Conundrum"
-    }
+    const TITLE: &'static str = "Cube Conundrum";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let games = input
             .split('\n')
             .map(|line| {
-                let (prefix, game) = line.split_once(": ").unwrap();
-                let id = prefix.split_once(' ').unwrap().1.parse::<usize>().unwrap();
+                let (prefix, game) = split_once(input, line, ": ")?;
+                let id = number(input, split_once(input, prefix, " ")?.1)?;
                 let cubes = game
                     .split("; ")
                     .map(|cubes_str| {
@@ -39,21 +40,27 @@ impl<'i> Day<'i> for Day02 {
                             green: 0,
                             blue: 0,
                         };
-                        cubes_str.split(", ").for_each(|cube_str| {
-                            match cube_str.split_once(' ').unwrap() {
-                                (num, "red") => cube_struct.red = num.parse::<usize>().unwrap(),
-                                (num, "green") => cube_struct.green = num.parse::<usize>().unwrap(),
-                                (num, "blue") => cube_struct.blue = num.parse::<usize>().unwrap(),
-                                _ => unreachable!(),
+                        for cube_str in cubes_str.split(", ") {
+                            match split_once(input, cube_str, " ")? {
+                                (num, "red") => cube_struct.red = number(input, num)?,
+                                (num, "green") => cube_struct.green = number(input, num)?,
+                                (num, "blue") => cube_struct.blue = number(input, num)?,
+                                (_, color) => {
+                                    return Err(ParseError::new(
+                                        input,
+                                        color,
+                                        "expected red, green or blue",
+                                    ))
+                                }
                             }
-                        });
-                        cube_struct
+                        }
+                        Ok(cube_struct)
                     })
-                    .collect::<Vec<Cubes>>();
-                Game { id, cubes }
+                    .collect::<ParseResult<Vec<Cubes>>>()?;
+                Ok(Game { id, cubes })
             })
-            .collect::<Vec<Game>>();
-        Self { games }
+            .collect::<ParseResult<Vec<Game>>>()?;
+        Ok(Self { games })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
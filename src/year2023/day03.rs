@@ -2,7 +2,8 @@
 
 use crate::{
     grids::IPoint,
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
@@ -24,11 +25,9 @@ impl<'i> Day<'i> for Day03 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Gear Ratios"
-    }
+    const TITLE: &'static str = "Gear Ratios";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut numbers = Vec::new();
         let mut symbols = HashSet::new();
         let mut gears = Vec::new();
@@ -61,11 +60,11 @@ impl<'i> Day<'i> for Day03 {
                 }
             }
         });
-        Self {
+        Ok(Self {
             numbers,
             symbols,
             gears,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> usize {
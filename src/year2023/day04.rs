@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/4
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use std::collections::HashSet;
 
 type Card = (Vec<usize>, HashSet<usize>);
@@ -13,27 +16,26 @@ impl<'i> Day<'i> for Day04 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Scratchcards"
-    }
+    const TITLE: &'static str = "Scratchcards";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let cards = input
             .split('\n')
             .map(|line| {
-                let (winning, card) = line.split_once(": ").unwrap().1.split_once(" | ").unwrap();
-                (
+                let (_, numbers) = split_once(input, line, ": ")?;
+                let (winning, card) = split_once(input, numbers, " | ")?;
+                Ok((
                     winning
                         .split_whitespace()
-                        .map(|num| num.parse::<usize>().unwrap())
-                        .collect::<Vec<usize>>(),
+                        .map(|num| number(input, num))
+                        .collect::<ParseResult<Vec<usize>>>()?,
                     card.split_whitespace()
-                        .map(|num| num.parse::<usize>().unwrap())
-                        .collect::<HashSet<usize>>(),
-                )
+                        .map(|num| number(input, num))
+                        .collect::<ParseResult<HashSet<usize>>>()?,
+                ))
             })
-            .collect::<Vec<Card>>();
-        Self { cards }
+            .collect::<ParseResult<Vec<Card>>>()?;
+        Ok(Self { cards })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/5
 
-use crate::solution::{Day, InputType};
+use crate::{
+    parsing::{number, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 
 struct Map {
@@ -27,38 +30,33 @@ impl<'i> Day<'i> for Day05 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "If You Give A Seed A Fertilizer"
-    }
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut iter = input.split("\n\n");
         let seeds = iter
             .next()
             .unwrap()
             .split(' ')
             .skip(1)
-            .map(|num| num.parse::<usize>().unwrap())
-            .collect::<Vec<usize>>();
+            .map(|num| number(input, num))
+            .collect::<ParseResult<Vec<usize>>>()?;
         let maps = iter
             .map(|m| {
                 m.split('\n')
                     .skip(1)
-                    .map(|line| {
-                        let values = line
-                            .split(' ')
-                            .map(|v| v.parse::<usize>().unwrap())
-                            .collect::<Vec<usize>>();
-                        Map {
-                            dst: values[0],
-                            src: values[1],
-                            num: values[2],
-                        }
+                    .map(|line| match line.split(' ').collect::<Vec<&str>>()[..] {
+                        [dst, src, num] => Ok(Map {
+                            dst: number(input, dst)?,
+                            src: number(input, src)?,
+                            num: number(input, num)?,
+                        }),
+                        _ => Err(ParseError::new(input, line, "expected three numbers")),
                     })
-                    .collect::<Vec<Map>>()
+                    .collect::<ParseResult<Vec<Map>>>()
             })
-            .collect::<Vec<Vec<Map>>>();
-        Self { seeds, maps }
+            .collect::<ParseResult<Vec<Vec<Map>>>>()?;
+        Ok(Self { seeds, maps })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
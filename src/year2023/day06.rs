@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/6
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 
 type Race = (/*time:*/ f64, /*distance:*/ f64);
 
@@ -13,11 +16,9 @@ impl<'i> Day<'i> for Day06 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Wait For It"
-    }
+    const TITLE: &'static str = "Wait For It";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let lines = input
             .split('\n')
             .map(|line| line.split_whitespace().collect::<Vec<&str>>())
@@ -34,7 +35,7 @@ impl<'i> Day<'i> for Day06 {
             lines[0][1..].join("").parse::<f64>().unwrap(),
             lines[1][1..].join("").parse::<f64>().unwrap(),
         );
-        Self { races, merged }
+        Ok(Self { races, merged })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/7
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use std::{cmp::Ordering, collections::HashMap};
 
@@ -146,15 +149,13 @@ impl<'i> Day<'i> for Day07 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Camel Cards"
-    }
+    const TITLE: &'static str = "Camel Cards";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let hands = input
             .split('\n')
             .map(|line| {
-                let (cards, bet) = line.split_once(' ').unwrap();
+                let (cards, bet) = split_once(input, line, " ")?;
                 let cards = cards.chars().map(Card::from_char).collect::<Vec<Card>>();
                 let mut counts = cards.clone().into_iter().counts();
                 let hand_type = HandType::from_counts(&counts);
@@ -170,16 +171,16 @@ impl<'i> Day<'i> for Day07 {
                 }
                 let hand_type_2 = HandType::from_counts(&counts);
 
-                let bet = bet.parse::<usize>().unwrap();
-                Hand {
+                let bet = number(input, bet)?;
+                Ok(Hand {
                     cards,
                     hand_type,
                     hand_type_2,
                     bet,
-                }
+                })
             })
-            .collect::<Vec<Hand>>();
-        Self { hands }
+            .collect::<ParseResult<Vec<Hand>>>()?;
+        Ok(Self { hands })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/8
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType},
+};
 use num::Integer;
 use regex::Regex;
 use std::collections::HashMap;
@@ -27,11 +30,9 @@ impl<'i> Day<'i> for Day08 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Haunted Wasteland"
-    }
+    const TITLE: &'static str = "Haunted Wasteland";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let tree_re = Regex::new(r"([A-Z0-9]{3}) = \(([A-Z0-9]{3}), ([A-Z0-9]{3})\)").unwrap();
         let (steps, tree, starts) = input
             .split_once("\n\n")
@@ -62,11 +63,11 @@ impl<'i> Day<'i> for Day08 {
                 (steps, tree, starts)
             })
             .unwrap();
-        Self {
+        Ok(Self {
             steps,
             tree,
             starts,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/9
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseResult},
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use num::Integer;
 
@@ -14,18 +17,18 @@ impl<'i> Day<'i> for Day09 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Mirage Maintenance"
-    }
+    const TITLE: &'static str = "Mirage Maintenance";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let diffs_vec = input
             .split('\n')
             .map(|line| {
                 line.split(' ')
-                    .map(|num| num.parse::<isize>().unwrap())
-                    .collect::<Vec<isize>>()
+                    .map(|num| number(input, num))
+                    .collect::<ParseResult<Vec<isize>>>()
             })
+            .collect::<ParseResult<Vec<Vec<isize>>>>()?
+            .into_iter()
             // Calculate the diffs during parsing as we need them for both parts.
             .map(|seq| {
                 let mut diffs = vec![seq];
@@ -43,7 +46,7 @@ impl<'i> Day<'i> for Day09 {
                 diffs
             })
             .collect::<Vec<Diffs>>();
-        Self { diffs_vec }
+        Ok(Self { diffs_vec })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Dir, UPoint},
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 use std::collections::{HashSet, VecDeque};
 
@@ -37,11 +38,9 @@ impl<'i> Day<'i> for Day10 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Pipe Maze"
-    }
+    const TITLE: &'static str = "Pipe Maze";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut start = UPoint::new(0, 0);
         let map = input
             .split('\n')
@@ -65,7 +64,7 @@ impl<'i> Day<'i> for Day10 {
                     .collect::<Vec<Tile>>()
             })
             .collect::<Vec<Vec<Tile>>>();
-        Self { map, start }
+        Ok(Self { map, start })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
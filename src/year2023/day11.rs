@@ -1,22 +1,24 @@
 // https://adventofcode.com/2023/day/11
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::ParseResult,
+    solution::{Day, InputType, Param, Params},
+};
 use itertools::Itertools;
 
 pub struct Day11 {
     xs: Vec<usize>,
     ys: Vec<usize>,
+    expansions: (usize, usize),
 }
 
 impl<'i> Day<'i> for Day11 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Cosmic Expansion"
-    }
+    const TITLE: &'static str = "Cosmic Expansion";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let (mut xs, mut ys) = (Vec::new(), Vec::new());
         input.split('\n').enumerate().for_each(|(y, line)| {
             line.chars().enumerate().for_each(|(x, c)| {
@@ -29,14 +31,50 @@ impl<'i> Day<'i> for Day11 {
         // ys is sorted by definition.
         // Could be faster if xs would be guaranteed sorted as well (BinaryHeap?).
         xs.sort();
-        Self { xs, ys }
+        Ok(Self {
+            xs,
+            ys,
+            expansions: (0, 0),
+        })
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part_1_expansion",
+            examples: 2,
+            puzzles: 2,
+        },
+        Param {
+            name: "part_2_expansion",
+            examples: 1_000_000,
+            puzzles: 1_000_000,
+        },
+    ];
+
+    fn set_params(&mut self, params: &Params) -> Result<(), String> {
+        self.expansions = (
+            params.get("part_1_expansion")?,
+            params.get("part_2_expansion")?,
+        );
+        // Every empty row or column is at least the one it was.
+        for (name, expansion) in [
+            ("part_1_expansion", self.expansions.0),
+            ("part_2_expansion", self.expansions.1),
+        ] {
+            if expansion == 0 {
+                return Err(format!("{} can't be {}", name, expansion));
+            }
+        }
+        Ok(())
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
-        self.galaxy_dist_1d(&self.xs, 2) + self.galaxy_dist_1d(&self.ys, 2)
+        let expand_by = self.expansions.0;
+        self.galaxy_dist_1d(&self.xs, expand_by) + self.galaxy_dist_1d(&self.ys, expand_by)
     }
     fn solve_part_2(&self) -> Self::Part2Output {
-        self.galaxy_dist_1d(&self.xs, 1_000_000) + self.galaxy_dist_1d(&self.ys, 1_000_000)
+        let expand_by = self.expansions.1;
+        self.galaxy_dist_1d(&self.xs, expand_by) + self.galaxy_dist_1d(&self.ys, expand_by)
     }
 
     fn solution(
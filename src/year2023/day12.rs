@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/12
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 use std::{collections::HashMap, iter};
 
 type Cache = HashMap<(usize, usize, usize), usize>;
@@ -25,35 +28,37 @@ impl<'i> Day<'i> for Day12 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Hot Springs"
-    }
+    const TITLE: &'static str = "Hot Springs";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let rows = input
             .split('\n')
             .map(|line| {
-                let (springs, contiguous) = line.split_once(' ').unwrap();
+                let (springs, contiguous) = split_once(input, line, " ")?;
                 let springs = springs
-                    .chars()
-                    .map(|c| match c {
-                        '.' => Status::Operational,
-                        '#' => Status::Damaged,
-                        '?' => Status::Unknown,
-                        _ => unreachable!(),
+                    .char_indices()
+                    .map(|(idx, c)| match c {
+                        '.' => Ok(Status::Operational),
+                        '#' => Ok(Status::Damaged),
+                        '?' => Ok(Status::Unknown),
+                        _ => Err(ParseError::new(
+                            input,
+                            &springs[idx..idx + c.len_utf8()],
+                            "expected '.', '#' or '?'",
+                        )),
                     })
-                    .collect::<Vec<Status>>();
+                    .collect::<ParseResult<Vec<Status>>>()?;
                 let contiguous = contiguous
                     .split(',')
-                    .map(|n| n.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>();
-                Row {
+                    .map(|n| number(input, n))
+                    .collect::<ParseResult<Vec<usize>>>()?;
+                Ok(Row {
                     springs,
                     contiguous,
-                }
+                })
             })
-            .collect::<Vec<Row>>();
-        Self { rows }
+            .collect::<ParseResult<Vec<Row>>>()?;
+        Ok(Self { rows })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -69,8 +74,7 @@ impl<'i> Day<'i> for Day12 {
         self.rows
             .iter()
             .map(|row| {
-                let springs = iter::repeat(row.springs.clone())
-                    .take(5)
+                let springs = iter::repeat_n(row.springs.clone(), 5)
                     .collect::<Vec<Vec<Status>>>()
                     .join(&Status::Unknown);
                 let contiguous = row.contiguous.repeat(5);
@@ -2,7 +2,8 @@
 
 use crate::{
     grids::Grid,
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 
 #[derive(PartialEq, Eq, Debug)]
@@ -19,85 +20,29 @@ impl<'i> Day<'i> for Day13 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Point of Incidence"
-    }
+    const TITLE: &'static str = "Point of Incidence";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let maps = input
             .split("\n\n")
             .map(|map| {
-                let width = map.find('\n').unwrap();
-                let tiles = map
-                    .chars()
-                    .filter_map(|c| match c {
-                        '.' => Some(Tile::Ash),
-                        '#' => Some(Tile::Rock),
-                        _ => None,
-                    })
-                    .collect::<Vec<Tile>>();
-                Grid::from_vec(tiles, width)
+                Grid::parse_in(input, map, |c| match c {
+                    '.' => Some(Tile::Ash),
+                    '#' => Some(Tile::Rock),
+                    _ => None,
+                })
             })
-            .collect::<Vec<Grid<Tile>>>();
-        Self { maps }
+            .collect::<ParseResult<Vec<Grid<Tile>>>>()?;
+        Ok(Self { maps })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
-        self.maps
-            .iter()
-            .map(|grid| {
-                if let Some(mirror_loc) = (1..grid.height()).find(|mirror| {
-                    // Check if iters are equal if we place horizontal mirror at row `mirror`.
-                    let iter_left = grid
-                        .iter_rows()
-                        .rev()
-                        .skip(grid.height() - mirror)
-                        .flatten();
-                    let iter_right = grid.iter_rows().skip(*mirror).flatten();
-                    iter_left.zip(iter_right).all(|(x, y)| x == y)
-                }) {
-                    mirror_loc * 100
-                } else if let Some(mirror_loc) = (1..grid.width()).find(|mirror| {
-                    // Check if iters are equal if we place vertical mirror at column `mirror`.
-                    let iter_up = grid.iter_cols().rev().skip(grid.width() - mirror).flatten();
-                    let iter_down = grid.iter_cols().skip(*mirror).flatten();
-                    iter_up.zip(iter_down).all(|(x, y)| x == y)
-                }) {
-                    mirror_loc
-                } else {
-                    panic!()
-                }
-            })
-            .sum::<usize>()
+        self.summarize(0)
     }
 
     fn solve_part_2(&self) -> Self::Part2Output {
-        self.maps
-            .iter()
-            .map(|grid| {
-                if let Some(mirror_loc) = (1..grid.height()).find(|mirror| {
-                    // Check if iters differ by 1 if we place horizontal mirror at row `mirror`.
-                    let iter_left = grid
-                        .iter_rows()
-                        .rev()
-                        .skip(grid.height() - mirror)
-                        .flatten();
-                    let iter_right = grid.iter_rows().skip(*mirror).flatten();
-                    iter_left.zip(iter_right).filter(|(x, y)| x != y).count() == 1
-                }) {
-                    mirror_loc * 100
-                } else if let Some(mirror_loc) = (1..grid.width()).find(|mirror| {
-                    // Check if iters differ by 1 if we place vertical mirror at column `mirror`.
-                    let iter_up = grid.iter_cols().rev().skip(grid.width() - mirror).flatten();
-                    let iter_down = grid.iter_cols().skip(*mirror).flatten();
-                    iter_up.zip(iter_down).filter(|(x, y)| x != y).count() == 1
-                }) {
-                    mirror_loc
-                } else {
-                    panic!()
-                }
-            })
-            .sum::<usize>()
+        // The smudge is the one cell that differs from its reflection.
+        self.summarize(1)
     }
 
     fn solution(
@@ -110,3 +55,24 @@ impl<'i> Day<'i> for Day13 {
         }
     }
 }
+
+impl Day13 {
+    fn summarize(&self, smudges: usize) -> usize {
+        self.maps
+            .iter()
+            .map(|grid| {
+                if let Some(row) =
+                    (1..grid.height()).find(|&row| grid.row_mirror_diff(row) == smudges)
+                {
+                    row * 100
+                } else if let Some(col) =
+                    (1..grid.width()).find(|&col| grid.col_mirror_diff(col) == smudges)
+                {
+                    col
+                } else {
+                    panic!()
+                }
+            })
+            .sum::<usize>()
+    }
+}
@@ -1,10 +1,10 @@
 // https://adventofcode.com/2023/day/14
 
 use crate::{
-    grids::Grid,
+    grids::{Grid, UPoint},
+    parsing::ParseResult,
     solution::{Day, InputType},
 };
-use itertools::Itertools;
 use std::collections::HashMap;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -22,23 +22,16 @@ impl<'i> Day<'i> for Day14 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Parabolic Reflector Dish"
-    }
+    const TITLE: &'static str = "Parabolic Reflector Dish";
 
-    fn parse(input: &'i str) -> Self {
-        let width = input.find('\n').unwrap();
-        let vec = input
-            .chars()
-            .filter_map(|c| match c {
-                '.' => Some(Rock::Empty),
-                'O' => Some(Rock::Round),
-                '#' => Some(Rock::Square),
-                _ => None,
-            })
-            .collect_vec();
-        let grid = Grid::from_vec(vec, width);
-        Self { grid }
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let grid = Grid::parse(input, |c| match c {
+            '.' => Some(Rock::Empty),
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Square),
+            _ => None,
+        })?;
+        Ok(Self { grid })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -68,21 +61,12 @@ impl<'i> Day<'i> for Day14 {
             }
             prev_states.insert(cur_grid.as_vec().clone(), i);
             i -= 1;
-            // Move north
-            let new_vec = self.move_and_flatten(cur_grid.iter_cols());
-            cur_grid = Grid::from_vec(new_vec, cur_grid.height()).transposed();
-
-            // Move west
-            let new_vec = self.move_and_flatten(cur_grid.iter_rows());
-            cur_grid = Grid::from_vec(new_vec, cur_grid.width());
-
-            // Move south
-            let new_vec = self.rev_move_and_flatten(cur_grid.iter_cols());
-            cur_grid = Grid::from_vec(new_vec, cur_grid.height()).transposed();
-
-            // Move east
-            let new_vec = self.rev_move_and_flatten(cur_grid.iter_rows());
-            cur_grid = Grid::from_vec(new_vec, cur_grid.width());
+            // Tilt north, west, south and east, by rotating the side
+            // to tilt towards to the north each time.
+            for _ in 0..4 {
+                Self::tilt_north(&mut cur_grid);
+                cur_grid.rotate_cw();
+            }
         }
         // Calculate weight score
         cur_grid
@@ -110,46 +94,21 @@ impl<'i> Day<'i> for Day14 {
 }
 
 impl Day14 {
-    fn move_and_flatten<'a, I, J>(&self, iter: I) -> Vec<Rock>
-    where
-        I: Iterator<Item = J> + DoubleEndedIterator + ExactSizeIterator,
-        J: Iterator<Item = &'a Rock> + DoubleEndedIterator + ExactSizeIterator,
-    {
-        iter.flat_map(|subiter| self.move_line(subiter))
-            .collect::<Vec<Rock>>()
-    }
-
-    fn rev_move_and_flatten<'a, I, J>(&self, iter: I) -> Vec<Rock>
-    where
-        I: Iterator<Item = J> + DoubleEndedIterator + ExactSizeIterator,
-        J: Iterator<Item = &'a Rock> + DoubleEndedIterator + ExactSizeIterator,
-    {
-        iter.flat_map(|subiter| {
-            let mut vec = self.move_line(subiter.rev());
-            vec.reverse();
-            vec
-        })
-        .collect::<Vec<Rock>>()
-    }
-
-    fn move_line<'a, I>(&self, iter: I) -> Vec<Rock>
-    where
-        I: Iterator<Item = &'a Rock> + ExactSizeIterator,
-    {
-        let mut vec = Vec::with_capacity(iter.len());
-        let mut prev_empty = 0;
-        iter.enumerate().for_each(|(i, t)| match t {
-            Rock::Empty => vec.push(Rock::Empty),
-            Rock::Square => {
-                prev_empty = i + 1;
-                vec.push(Rock::Square)
-            }
-            Rock::Round => {
-                vec.push(Rock::Empty);
-                vec[prev_empty] = Rock::Round;
-                prev_empty += 1;
+    fn tilt_north(grid: &mut Grid<Rock>) {
+        for x in 0..grid.width() {
+            let mut free = 0;
+            for y in 0..grid.height() {
+                let loc = UPoint::new(x, y);
+                match grid.get(&loc) {
+                    Rock::Empty => {}
+                    Rock::Square => free = y + 1,
+                    Rock::Round => {
+                        *grid.get_mut(&loc) = Rock::Empty;
+                        *grid.get_mut(&UPoint::new(x, free)) = Rock::Round;
+                        free += 1;
+                    }
+                }
             }
-        });
-        vec
+        }
     }
 }
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/15
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, ParseError, ParseResult},
+    solution::{Day, InputType},
+};
 
 enum Operation<'i> {
     Remove(&'i str),
@@ -16,26 +19,28 @@ impl<'i> Day<'i> for Day15<'i> {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Lens Library"
-    }
+    const TITLE: &'static str = "Lens Library";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let words = input.split(',').collect::<Vec<&str>>();
         let operations = words
             .clone()
             .iter()
             .map(|s| {
                 if let Some(label) = s.strip_suffix('-') {
-                    Operation::Remove(label)
+                    Ok(Operation::Remove(label))
                 } else if let Some((label, val)) = s.split_once('=') {
-                    Operation::Insert(label, val.parse::<usize>().unwrap())
+                    Ok(Operation::Insert(label, number(input, val)?))
                 } else {
-                    panic!()
+                    Err(ParseError::new(
+                        input,
+                        s,
+                        "expected a step like \"ab-\" or \"ab=1\"",
+                    ))
                 }
             })
-            .collect::<Vec<Operation>>();
-        Self { words, operations }
+            .collect::<ParseResult<Vec<Operation>>>()?;
+        Ok(Self { words, operations })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -4,7 +4,8 @@ use std::collections::{HashSet, VecDeque};
 
 use crate::{
     grids::{Dir, Grid, UPoint},
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 
 enum Tile {
@@ -57,25 +58,18 @@ impl<'i> Day<'i> for Day16 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "The Floor Will Be Lava"
-    }
+    const TITLE: &'static str = "The Floor Will Be Lava";
 
-    fn parse(input: &'i str) -> Self {
-        let width = input.find('\n').unwrap();
-        let vec = input
-            .chars()
-            .filter_map(|c| match c {
-                '.' => Some(Tile::Empty),
-                '/' => Some(Tile::Mirror),
-                '\\' => Some(Tile::BackMirror),
-                '-' => Some(Tile::HorSplitter),
-                '|' => Some(Tile::VerSplitter),
-                _ => None,
-            })
-            .collect::<Vec<Tile>>();
-        let grid = Grid::from_vec(vec, width);
-        Self { grid }
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let grid = Grid::parse(input, |c| match c {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::Mirror),
+            '\\' => Some(Tile::BackMirror),
+            '-' => Some(Tile::HorSplitter),
+            '|' => Some(Tile::VerSplitter),
+            _ => None,
+        })?;
+        Ok(Self { grid })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Dir, Grid, UPoint},
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 use std::{
     cmp::Ordering,
@@ -45,18 +46,11 @@ impl<'i> Day<'i> for Day17 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Clumsy Crucible"
-    }
+    const TITLE: &'static str = "Clumsy Crucible";
 
-    fn parse(input: &'i str) -> Self {
-        let width = input.find('\n').unwrap();
-        let vec = input
-            .chars()
-            .filter_map(|c| c.to_digit(10).map(|d| d as usize))
-            .collect::<Vec<usize>>();
-        let grid = Grid::from_vec(vec, width);
-        Self { grid }
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let grid = Grid::parse(input, |c| c.to_digit(10).map(|d| d as usize))?;
+        Ok(Self { grid })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -107,7 +101,7 @@ impl Day17 {
                             continue;
                         }
                         let key = (new_loc, new_dir);
-                        if costs.get(&key).map_or(true, |&cost| new_cost < cost) {
+                        if costs.get(&key).is_none_or(|&cost| new_cost < cost) {
                             costs.insert(key, new_cost);
                             let mut path = state.path.clone();
                             path.push(new_loc);
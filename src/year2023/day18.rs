@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Dir, IPoint},
-    solution::{InputType, Day},
+    parsing::{number, split_once, ParseError, ParseResult},
+    solution::{Day, InputType},
 };
 use itertools::Itertools;
 
@@ -15,39 +16,46 @@ impl<'i> Day<'i> for Day18 {
     type Part1Output = isize;
     type Part2Output = isize;
 
-    fn title(&self) -> &str {
-        "Lavaduct Lagoon"
-    }
+    const TITLE: &'static str = "Lavaduct Lagoon";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut path1 = Vec::new();
         let mut path2 = Vec::new();
-        input.split('\n').for_each(|line| {
-            let (path, hex) = line.split_once(" (#").unwrap();
-            let (dir, num) = path.split_once(' ').unwrap();
-            let dir = match dir.chars().next().unwrap() {
-                'U' => Dir::Up,
-                'R' => Dir::Right,
-                'D' => Dir::Down,
-                'L' => Dir::Left,
-                _ => unreachable!(),
+        for line in input.split('\n') {
+            let (path, hex) = split_once(input, line, " (#")?;
+            let (dir, num) = split_once(input, path, " ")?;
+            let dir = match dir {
+                "U" => Dir::Up,
+                "R" => Dir::Right,
+                "D" => Dir::Down,
+                "L" => Dir::Left,
+                _ => return Err(ParseError::new(input, dir, "expected U, R, D or L")),
             };
-            let num = num.parse::<isize>().unwrap();
+            let num = number(input, num)?;
             path1.push((dir, num));
 
-            let hex = &hex[..hex.len() - 1];
-            let (num, dir) = hex.split_at(hex.len() - 1);
-            let dir = match dir.chars().next().unwrap() {
-                '0' => Dir::Right,
-                '1' => Dir::Down,
-                '2' => Dir::Left,
-                '3' => Dir::Up,
-                _ => unreachable!(),
+            let (num, dir) = match hex.strip_suffix(')') {
+                Some(hex) if hex.len() == 6 && hex.is_ascii() => hex.split_at(5),
+                _ => {
+                    return Err(ParseError::new(
+                        input,
+                        hex,
+                        "expected a color like \"70c710)\"",
+                    ))
+                }
+            };
+            let dir = match dir {
+                "0" => Dir::Right,
+                "1" => Dir::Down,
+                "2" => Dir::Left,
+                "3" => Dir::Up,
+                _ => return Err(ParseError::new(input, dir, "expected a digit from 0 to 3")),
             };
-            let num = isize::from_str_radix(num, 16).unwrap();
+            let num = isize::from_str_radix(num, 16)
+                .map_err(|err| ParseError::new(input, num, err.to_string()))?;
             path2.push((dir, num));
-        });
-        Self { path1, path2 }
+        }
+        Ok(Self { path1, path2 })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/19
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -142,39 +145,37 @@ impl<'i> Day<'i> for Day19 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Aplenty"
-    }
+    const TITLE: &'static str = "Aplenty";
 
-    fn parse(input: &'i str) -> Self {
-        let (flows, things) = input.split_once("\n\n").unwrap();
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let (flows, things) = split_once(input, input, "\n\n")?;
         let flows = flows
             .split('\n')
             .map(|line| {
-                let (label, flow) = line.split_once('{').unwrap();
+                let (label, flow) = split_once(input, line, "{")?;
                 let flow = flow[..flow.len() - 1]
                     .split(',')
                     .map(Statement::from_str)
                     .collect::<Vec<Statement>>();
-                (label.to_string(), Statements(flow))
+                Ok((label.to_string(), Statements(flow)))
             })
-            .collect::<HashMap<String, Statements>>();
+            .collect::<ParseResult<HashMap<String, Statements>>>()?;
         let things = things
             .split('\n')
             .map(|thing| {
                 let vals = thing[1..thing.len() - 1]
                     .split(',')
-                    .map(|cat| cat.split_once('=').unwrap().1.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>();
-                Thing {
+                    .map(|cat| number(input, split_once(input, cat, "=")?.1))
+                    .collect::<ParseResult<Vec<usize>>>()?;
+                Ok(Thing {
                     x: vals[0],
                     m: vals[1],
                     a: vals[2],
                     s: vals[3],
-                }
+                })
             })
-            .collect::<Vec<Thing>>();
-        Self { flows, things }
+            .collect::<ParseResult<Vec<Thing>>>()?;
+        Ok(Self { flows, things })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
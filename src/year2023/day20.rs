@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/20
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use num::Integer;
 use std::collections::{HashMap, VecDeque};
 
@@ -54,15 +57,13 @@ impl<'i> Day<'i> for Day20 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Pulse Propagation"
-    }
+    const TITLE: &'static str = "Pulse Propagation";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut config = input
             .split('\n')
             .map(|line| {
-                let (module, cables) = line.split_once(" -> ").unwrap();
+                let (module, cables) = split_once(input, line, " -> ")?;
                 let (module_type, module_label) = match module.split_at(1) {
                     ("%", label) => (Module::FlipFlop(false), label.to_string()),
                     ("&", label) => (Module::Conjunction(HashMap::new()), label.to_string()),
@@ -72,9 +73,9 @@ impl<'i> Day<'i> for Day20 {
                     .split(", ")
                     .map(|s| s.to_string())
                     .collect::<Vec<String>>();
-                (module_label, (module_type, cables))
+                Ok((module_label, (module_type, cables)))
             })
-            .collect::<Configuration>();
+            .collect::<ParseResult<Configuration>>()?;
 
         // Fill the maps of all conjunctions with its sources.
         let src_dsts = config
@@ -89,7 +90,7 @@ impl<'i> Day<'i> for Day20 {
                 }
             }
         }
-        Self { config }
+        Ok(Self { config })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
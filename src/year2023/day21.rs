@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Grid, IPoint, UPoint},
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType, Param, Params},
 };
 use std::collections::{HashSet, VecDeque};
 
@@ -14,63 +15,77 @@ enum Tile {
 
 pub struct Day21 {
     grid: Grid<Tile>,
+    start: UPoint,
+    steps: (usize, usize),
 }
 
 impl<'i> Day<'i> for Day21 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Step Counter"
+    const TITLE: &'static str = "Step Counter";
+
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let (grid, [start]) = Grid::parse_marked(input, ['S'], |c| match c {
+            '.' | 'S' => Some(Tile::Empty),
+            '#' => Some(Tile::Rock),
+            _ => None,
+        })?;
+        Ok(Self {
+            grid,
+            start,
+            steps: (0, 0),
+        })
     }
 
-    fn parse(input: &'i str) -> Self {
-        let width = input.find('\n').unwrap();
-
-        let vec = input
-            .chars()
-            .filter_map(|c| match c {
-                '.' | 'S' => Some(Tile::Empty),
-                '#' => Some(Tile::Rock),
-                _ => None,
-            })
-            .collect::<Vec<Tile>>();
-        let grid = Grid::from_vec(vec, width);
-        Self { grid }
-    }
-
-    fn solve_part_1(&self) -> Self::Part1Output {
-        // Hardcode target differently for example input
-        let target = if self.grid.width() < 64 { 6 } else { 64 };
-        // Start is always in the center for the example and puzzle input.
-        let start = UPoint::new(self.grid.width() / 2, self.grid.height() / 2);
-
-        let mut queue = VecDeque::from([(0, start)]);
-        let mut visited = HashSet::new();
-        let mut finish = HashSet::new();
-        while let Some((steps, state)) = queue.pop_front() {
-            if steps % 2 == 0 {
-                finish.insert(state);
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part_1_steps",
+            examples: 6,
+            puzzles: 64,
+        },
+        Param {
+            name: "part_2_steps",
+            examples: 10,
+            puzzles: 26501365,
+        },
+    ];
+
+    fn set_params(&mut self, params: &Params) -> Result<(), String> {
+        self.steps = (params.get("part_1_steps")?, params.get("part_2_steps")?);
+        let steps = self.steps.1;
+        if steps >= self.loop_steps() {
+            // See `solve_part_2` for when the counts can be extrapolated.
+            let width = self.grid.width();
+            if !self.start_lines_clear() {
+                return Err(format!(
+                    "part_2_steps can't be {}, as the row and column of the start are not empty \
+                     (only up to {} steps are supported)",
+                    steps,
+                    self.loop_steps() - 1
+                ));
             }
-            if steps < target {
-                let neighbors = state
-                    .neighbors_4()
-                    .iter()
-                    .filter(|p| *self.grid.get(p) != Tile::Rock && !visited.contains(*p))
-                    .copied()
-                    .collect::<Vec<UPoint>>();
-                for neighbor in neighbors {
-                    visited.insert(neighbor);
-                    queue.push_back((steps + 1, neighbor));
-                }
+            if steps % width != width / 2 || (steps / width) % 2 == 1 {
+                return Err(format!(
+                    "part_2_steps can't be {}, as it needs to be below {} \
+                     or an even number of grid widths ({}) past {}",
+                    steps,
+                    self.loop_steps(),
+                    width,
+                    width / 2
+                ));
             }
         }
-        finish.len()
+        Ok(())
+    }
+
+    fn solve_part_1(&self) -> Self::Part1Output {
+        self.reachable(self.steps.0)
     }
 
     fn solve_part_2(&self) -> Self::Part2Output {
-        if self.grid.width() < 64 {
-            return 0;
+        if self.steps.1 < self.loop_steps() {
+            return self.reachable(self.steps.1);
         }
         // When running this simulation for a smaller number of steps,
         // but with the same remainder of steps for the edge-grids,
@@ -105,27 +120,21 @@ impl<'i> Day<'i> for Day21 {
         // This only works because the row and column of the start
         // position are completely empty. For the original example,
         // this code does not work because these are not empty,
-        // which is why it's not supported.
+        // which is why it's only counted directly for few steps.
 
         // NOTE: 7x7 has different edge-values, so I omitted it,
         // and decided to only support 5x5, 9x9, 13x13, 17x17 etc,
         // which the input also falls in.
 
-        // Hardcode target differently for example input
-        let total_steps = 26501365;
+        let total_steps = self.steps.1;
         let mod_target = total_steps % 2;
         let dim = total_steps / self.grid.width();
-        if dim % 2 == 1 {
-            panic!("Uneven dim not supported");
-        }
 
-        let loop_steps = (self.grid.width() / 2) + (self.grid.width() * 2);
+        let loop_steps = self.loop_steps();
         let small_target = loop_steps + ((total_steps - loop_steps) % self.grid.width());
 
-        let iwidth = self.grid.width() as isize;
-        let iheight = self.grid.height() as isize;
-
-        let start = IPoint::new(iwidth / 2, iheight / 2);
+        let garden = self.grid.tiled();
+        let start = IPoint::new(self.start.x as isize, self.start.y as isize);
 
         let mut queue = VecDeque::from([(0, start)]);
         let mut visited = HashSet::new();
@@ -133,23 +142,15 @@ impl<'i> Day<'i> for Day21 {
 
         while let Some((steps, state)) = queue.pop_front() {
             if steps % 2 == mod_target {
-                let grid_loc = UPoint::new(
-                    (state.x + iwidth * 2) as usize / self.grid.width(),
-                    (state.y + iheight * 2) as usize / self.grid.height(),
-                );
+                let tile = garden.tile(&state);
+                let grid_loc = UPoint::new((tile.x + 2) as usize, (tile.y + 2) as usize);
                 finish_grid.get_mut(&grid_loc).insert(state);
             }
             if steps < small_target {
                 let neighbors = state
                     .neighbors_4()
                     .iter()
-                    .filter(|p| {
-                        *self.grid.get(&UPoint::new(
-                            p.x.rem_euclid(iwidth) as usize,
-                            p.y.rem_euclid(iheight) as usize,
-                        )) != Tile::Rock
-                            && !visited.contains(*p)
-                    })
+                    .filter(|p| *garden.get(p) != Tile::Rock && !visited.contains(*p))
                     .copied()
                     .collect::<Vec<IPoint>>();
                 for neighbor in neighbors {
@@ -190,8 +191,53 @@ impl<'i> Day<'i> for Day21 {
         input_type: crate::solution::InputType,
     ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
         match input_type {
-            InputType::Examples => (Some(16), Some(0)),
+            InputType::Examples => (Some(16), Some(50)),
             InputType::Puzzles => (Some(3646), Some(606188414811259)),
         }
     }
 }
+
+impl Day21 {
+    // Steps from the start to the far edge of the grids two grids away.
+    fn loop_steps(&self) -> usize {
+        (self.grid.width() / 2) + (self.grid.width() * 2)
+    }
+
+    fn start_lines_clear(&self) -> bool {
+        self.grid.width() == self.grid.height()
+            && self.start.x == self.grid.width() / 2
+            && self.start.y == self.grid.height() / 2
+            && (0..self.grid.width())
+                .all(|x| *self.grid.get(&UPoint::new(x, self.start.y)) == Tile::Empty)
+            && (0..self.grid.height())
+                .all(|y| *self.grid.get(&UPoint::new(self.start.x, y)) == Tile::Empty)
+    }
+
+    // Counts the plots reachable in exactly `target` steps, walking
+    // into the repeated grids when needed.
+    fn reachable(&self, target: usize) -> usize {
+        let garden = self.grid.tiled();
+        let start = IPoint::new(self.start.x as isize, self.start.y as isize);
+        let mut queue = VecDeque::from([(0, start)]);
+        let mut visited = HashSet::from([start]);
+        let mut finish = 0;
+        while let Some((steps, state)) = queue.pop_front() {
+            if steps % 2 == target % 2 {
+                finish += 1;
+            }
+            if steps < target {
+                let neighbors = state
+                    .neighbors_4()
+                    .iter()
+                    .filter(|p| *garden.get(p) != Tile::Rock && !visited.contains(*p))
+                    .copied()
+                    .collect::<Vec<IPoint>>();
+                for neighbor in neighbors {
+                    visited.insert(neighbor);
+                    queue.push_back((steps + 1, neighbor));
+                }
+            }
+        }
+        finish
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Grid, UPoint},
-    solution::{Day, InputType},
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType, SharedDay},
 };
 use std::ops::Deref;
 
@@ -17,23 +18,20 @@ impl<'i> Day<'i> for Day22 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Sand Slabs"
-    }
+    const TITLE: &'static str = "Sand Slabs";
 
-    fn parse(input: &'i str) -> Self {
-        let mut slabs = input
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let coords = |text: &str| -> ParseResult<[usize; 3]> {
+            let (x, rest) = split_once(input, text, ",")?;
+            let (y, z) = split_once(input, rest, ",")?;
+            Ok([number(input, x)?, number(input, y)?, number(input, z)?])
+        };
+        let slabs = input
             .split('\n')
             .map(|line| {
-                let (start, end) = line.split_once('~').unwrap();
-                let start = start
-                    .split(',')
-                    .map(|i| i.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>();
-                let end = end
-                    .split(',')
-                    .map(|i| i.parse::<usize>().unwrap())
-                    .collect::<Vec<usize>>();
+                let (start, end) = split_once(input, line, "~")?;
+                let start = coords(start)?;
+                let end = coords(end)?;
 
                 if let Some(diff_idx) = start.iter().zip(end.iter()).position(|ax| ax.0 != ax.1) {
                     let mut curr = start;
@@ -42,30 +40,56 @@ impl<'i> Day<'i> for Day22 {
                         slab.push((curr[0], curr[1], curr[2]));
                         curr[diff_idx] += 1;
                     }
-                    slab
+                    Ok(slab)
                 } else {
-                    vec![(start[0], start[1], start[2])]
+                    Ok(vec![(start[0], start[1], start[2])])
                 }
             })
-            .collect::<Vec<Slab>>();
+            .collect::<ParseResult<Vec<Slab>>>()?;
+        Ok(Self { slabs })
+    }
+
+    fn solve_part_1(&self) -> Self::Part1Output {
+        self.solve_part_1_with(&self.shared())
+    }
+
+    fn solve_part_2(&self) -> Self::Part2Output {
+        self.solve_part_2_with(&self.shared())
+    }
+
+    fn solution(
+        &self,
+        input_type: crate::solution::InputType,
+    ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
+        match input_type {
+            InputType::Examples => (Some(5), Some(7)),
+            InputType::Puzzles => (Some(426), Some(61920)),
+        }
+    }
+}
+
+impl<'i> SharedDay<'i> for Day22 {
+    type Shared = Vec<Slab>;
 
-        // Drop all the slabs already, since it's needed for both parts.
-        // It could be possible to instead structure the slabs as a tree
-        // based on which slabs support which, which could be nicer.
-        // For now, I implemented it the straightforward way.
+    // Drop all the slabs first, since it's needed for both parts.
+    // It could be possible to instead structure the slabs as a tree
+    // based on which slabs support which, which could be nicer.
+    // For now, I implemented it the straightforward way.
+    fn shared(&self) -> Self::Shared {
+        let mut slabs = self.slabs.clone();
         let mut height_map = Grid::from_vec(vec![0; 100], 10);
         slabs.sort_by(|a, b| a[0].2.cmp(&b[0].2));
         slabs.iter_mut().for_each(|slab| {
             Self::drop_slab(slab, &height_map);
             Self::update_height_map(slab, &mut height_map);
         });
-        Self { slabs }
+        slabs
     }
 
-    fn solve_part_1(&self) -> Self::Part1Output {
-        (0..self.slabs.len())
+    fn solve_part_1_with(&self, dropped: &Self::Shared) -> Self::Part1Output {
+        (0..dropped.len())
             .filter(|&idx| {
-                let mut slabs = self.slabs.clone();
+                let mut slabs = dropped.clone();
                 slabs.remove(idx);
                 let mut height_map = Grid::from_vec(vec![0; 100], 10);
                 for slab in &slabs {
@@ -82,10 +106,10 @@ impl<'i> Day<'i> for Day22 {
             .count()
     }
 
-    fn solve_part_2(&self) -> Self::Part2Output {
-        (0..self.slabs.len())
+    fn solve_part_2_with(&self, dropped: &Self::Shared) -> Self::Part2Output {
+        (0..dropped.len())
             .flat_map(|idx| {
-                let mut slabs = self.slabs.clone();
+                let mut slabs = dropped.clone();
                 slabs.remove(idx);
                 let mut height_map = Grid::from_vec(vec![0; 100], 10);
 
@@ -100,16 +124,6 @@ impl<'i> Day<'i> for Day22 {
             })
             .sum::<usize>()
     }
-
-    fn solution(
-        &self,
-        input_type: crate::solution::InputType,
-    ) -> (Option<Self::Part1Output>, Option<Self::Part2Output>) {
-        match input_type {
-            InputType::Examples => (Some(5), Some(7)),
-            InputType::Puzzles => (Some(426), Some(61920)),
-        }
-    }
 }
 
 impl Day22 {
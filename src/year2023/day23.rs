@@ -2,7 +2,8 @@
 
 use crate::{
     grids::{Dir, Grid, UPoint},
-    solution::{InputType, Day},
+    parsing::ParseResult,
+    solution::{Day, InputType},
 };
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -22,29 +23,22 @@ impl<'i> Day<'i> for Day23 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "A Long Walk"
-    }
+    const TITLE: &'static str = "A Long Walk";
 
-    fn parse(input: &'i str) -> Self {
-        let width = input.find('\n').unwrap();
-        let vec = input
-            .chars()
-            .filter_map(|c| match c {
-                '.' => Some(Tile::Empty),
-                '#' => Some(Tile::Wall),
-                '>' => Some(Tile::Slope(Dir::Right)),
-                'v' => Some(Tile::Slope(Dir::Down)),
-                '<' => Some(Tile::Slope(Dir::Left)),
-                '^' => Some(Tile::Slope(Dir::Up)),
-                _ => None,
-            })
-            .collect::<Vec<Tile>>();
-        let grid = Grid::from_vec(vec, width);
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let grid = Grid::parse(input, |c| match c {
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            '>' => Some(Tile::Slope(Dir::Right)),
+            'v' => Some(Tile::Slope(Dir::Down)),
+            '<' => Some(Tile::Slope(Dir::Left)),
+            '^' => Some(Tile::Slope(Dir::Up)),
+            _ => None,
+        })?;
         let start = UPoint::new(1, 0);
         let end = UPoint::new(grid.width() - 2, grid.height() - 1);
         let graph = Day23::to_graph(grid, UPoint::new(1, 0));
-        Self { graph, start, end }
+        Ok(Self { graph, start, end })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
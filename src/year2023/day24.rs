@@ -2,7 +2,8 @@
 
 use crate::{
     grids::FPoint,
-    solution::{InputType, Day},
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
 };
 use itertools::Itertools;
 use z3::{
@@ -20,29 +21,22 @@ impl<'i> Day<'i> for Day24 {
     type Part1Output = usize;
     type Part2Output = u64;
 
-    fn title(&self) -> &str {
-        "Never Tell Me The Odds"
-    }
+    const TITLE: &'static str = "Never Tell Me The Odds";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
+        let coords = |text: &str| -> ParseResult<Point3d> {
+            let (x, rest) = split_once(input, text, ", ")?;
+            let (y, z) = split_once(input, rest, ", ")?;
+            Ok((number(input, x)?, number(input, y)?, number(input, z)?))
+        };
         let lines = input
             .split('\n')
             .map(|line| {
-                let (point, velocity) = line.split_once(" @ ").unwrap();
-                let coords = point
-                    .split(", ")
-                    .map(|n| n.parse::<isize>().unwrap())
-                    .collect::<Vec<isize>>();
-                let point = (coords[0], coords[1], coords[2]);
-                let coords = velocity
-                    .split(", ")
-                    .map(|n| n.parse::<isize>().unwrap())
-                    .collect::<Vec<isize>>();
-                let velocity = (coords[0], coords[1], coords[2]);
-                (point, velocity)
+                let (point, velocity) = split_once(input, line, " @ ")?;
+                Ok((coords(point)?, coords(velocity)?))
             })
-            .collect::<Vec<(Point3d, Point3d)>>();
-        Self { lines }
+            .collect::<ParseResult<Vec<(Point3d, Point3d)>>>()?;
+        Ok(Self { lines })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
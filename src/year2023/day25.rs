@@ -1,6 +1,9 @@
 // https://adventofcode.com/2023/day/25
 
-use crate::solution::{InputType, Day};
+use crate::{
+    parsing::{split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Clone)]
@@ -19,16 +22,14 @@ impl<'i> Day<'i> for Day25<'i> {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Snowverload"
-    }
+    const TITLE: &'static str = "Snowverload";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut edges = Vec::new();
         let mut edge_map = HashMap::new();
         let mut nodes = HashSet::new();
-        input.split('\n').for_each(|line| {
-            let (k, vs) = line.split_once(": ").unwrap();
+        for line in input.split('\n') {
+            let (k, vs) = split_once(input, line, ": ")?;
             nodes.insert(k);
             for v in vs.split(' ') {
                 nodes.insert(v);
@@ -38,12 +39,12 @@ impl<'i> Day<'i> for Day25<'i> {
                 edge_map.entry(k).or_insert(Vec::new()).push(idx);
                 edge_map.entry(v).or_insert(Vec::new()).push(idx);
             }
-        });
-        Self {
+        }
+        Ok(Self {
             nodes,
             edges,
             edge_map,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
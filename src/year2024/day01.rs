@@ -1,6 +1,9 @@
 // https://adventofcode.com/2024/day/1
 
-use crate::solution::{Day, InputType};
+use crate::{
+    parsing::{number, split_once, ParseResult},
+    solution::{Day, InputType},
+};
 use std::collections::HashMap;
 
 pub struct Day01 {
@@ -13,30 +16,27 @@ impl<'i> Day<'i> for Day01 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "Historian Hysteria"
-    }
+    const TITLE: &'static str = "Historian Hysteria";
 
-    fn parse(input: &'i str) -> Self {
+    fn try_parse(input: &'i str) -> ParseResult<Self> {
         let mut right_count = HashMap::new();
         let (left, right): (Vec<usize>, Vec<usize>) = input
             .split('\n')
             .map(|line| {
-                line.split_once("   ")
-                    .map(|(l, r)| {
-                        let l = l.parse::<usize>().unwrap();
-                        let r = r.parse::<usize>().unwrap();
-                        *right_count.entry(r).or_insert(0) += 1;
-                        (l, r)
-                    })
-                    .unwrap()
+                let (l, r) = split_once(input, line, "   ")?;
+                let l = number::<usize>(input, l)?;
+                let r = number::<usize>(input, r)?;
+                *right_count.entry(r).or_insert(0) += 1;
+                Ok((l, r))
             })
+            .collect::<ParseResult<Vec<(usize, usize)>>>()?
+            .into_iter()
             .unzip();
-        Self {
+        Ok(Self {
             left,
             right,
             right_count,
-        }
+        })
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {
@@ -1,6 +1,6 @@
 // https://adventofcode.com/2024/day/2
 
-use crate::solution::{Day, InputType};
+use crate::{parsing::ParseResult, solution::Day};
 
 pub struct Day02 {}
 
@@ -8,12 +8,10 @@ impl<'i> Day<'i> for Day02 {
     type Part1Output = usize;
     type Part2Output = usize;
 
-    fn title(&self) -> &str {
-        "TODO"
-    }
+    const TITLE: &'static str = "TODO";
 
-    fn parse(input: &'i str) -> Self {
-        Self {}
+    fn try_parse(_input: &'i str) -> ParseResult<Self> {
+        Ok(Self {})
     }
 
     fn solve_part_1(&self) -> Self::Part1Output {